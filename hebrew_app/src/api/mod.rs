@@ -4,13 +4,16 @@
 
 use axum::{
     extract::{Query, State},
-    http::StatusCode,
-    response::Json,
+    http::{header, StatusCode},
+    response::{IntoResponse, Json},
     routing::get,
     Router,
 };
-use chrono::Datelike;
-use hebrew_core::{CalendarError, DailyData, HebrewCalendar};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc, Weekday};
+use hebrew_core::{
+    CalendarError, CalendarSystem, DailyData, DateConverter, GeoLocation, HebrewCalendar, HebrewYearInfo, Holiday,
+    HolidayCalculator, HolidayScheme, Zmanim, ZmanimCalculator,
+};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tower_http::cors::{Any, CorsLayer};
@@ -35,13 +38,30 @@ pub fn build_router(config: AppConfig) -> Router {
 
     Router::new()
         .route("/", get(root))
+        .merge(v1_routes())
+        .merge(v2_routes())
+        .layer(cors)
+        .with_state(state)
+}
+
+/// Version 1 routes: snake_case keys and "HH:MM" local times
+fn v1_routes() -> Router<Arc<ApiState>> {
+    Router::new()
         .route("/api/v1/health", get(health_check))
         .route("/api/v1/calendar/convert", get(convert_date))
         .route("/api/v1/calendar/range", get(date_range))
+        .route("/api/v1/calendar/summary", get(day_summary))
+        .route("/api/v1/calendar/year-info", get(year_info))
+        .route("/api/v1/calendar/feed.ics", get(calendar_feed))
         .route("/api/v1/zmanim", get(get_zmanim))
         .route("/api/v1/holidays/upcoming", get(upcoming_holidays))
-        .layer(cors)
-        .with_state(state)
+}
+
+/// Version 2 routes: camelCase keys and RFC 3339 timestamps
+fn v2_routes() -> Router<Arc<ApiState>> {
+    Router::new()
+        .route("/api/v2/health", get(health_check))
+        .route("/api/v2/zmanim", get(get_zmanim_v2))
 }
 
 /// Launch the API server
@@ -65,8 +85,14 @@ async fn root() -> &'static str {
     - GET /api/v1/health\n\
     - GET /api/v1/calendar/convert?date=YYYY-MM-DD&lat=LAT&long=LNG\n\
     - GET /api/v1/calendar/range?start=YYYY-MM-DD&end=YYYY-MM-DD&lat=LAT&long=LNG\n\
-    - GET /api/v1/zmanim?date=YYYY-MM-DD&lat=LAT&long=LNG&elevation=M\n\
-    - GET /api/v1/holidays/upcoming?year=YYYY\n"
+    - GET /api/v1/calendar/summary?date=YYYY-MM-DD&lat=LAT&long=LNG\n\
+    - GET /api/v1/calendar/year-info?hebrew_year=YYYY (or gregorian_year=YYYY)\n\
+    - GET /api/v1/calendar/feed.ics?lat=LAT&long=LNG&tz=ZONE&weeks=52&israel=BOOL\n\
+    - GET /api/v1/zmanim?date=YYYY-MM-DD&lat=LAT&long=LNG&elevation=M&tz=ZONE\n\
+    - GET /api/v1/holidays/upcoming?year=YYYY (default: the rest of this year)\n\n\
+    Version 2 (camelCase keys, RFC 3339 times):\n\
+    - GET /api/v2/health\n\
+    - GET /api/v2/zmanim?date=YYYY-MM-DD&lat=LAT&long=LNG&elevation=M&tz=ZONE\n"
 }
 
 /// Health check endpoint
@@ -112,7 +138,7 @@ async fn convert_date(
         let mut loc = hebrew_core::zmanim::GeoLocation::new(lat, long)
             .map_err(ApiError::from)?;
         if let Some(elev) = params.elevation {
-            loc = loc.with_elevation(elev);
+            loc = loc.with_elevation(elev).map_err(ApiError::from)?;
         }
         Some(loc)
     } else {
@@ -122,12 +148,50 @@ async fn convert_date(
     let candle_offset = params.candle_offset
         .unwrap_or(state.config.candle_lighting_offset_minutes);
     
-    let data = HebrewCalendar::calculate_day(date, location, candle_offset)
+    let data = HebrewCalendar::calculate_day(date, CalendarSystem::Gregorian, location, candle_offset)
         .map_err(ApiError::from)?;
     
     Ok(Json(data))
 }
 
+/// Compact "is today special" summary for widgets
+#[derive(Serialize)]
+pub struct DaySummary {
+    hebrew_date: String,
+    is_shabbat: bool,
+    is_yom_tov: bool,
+    is_fast: bool,
+    is_rosh_chodesh: bool,
+    /// The leading holiday's name, or "Shabbat"
+    primary_label: Option<String>,
+    candle_lighting: Option<String>,
+}
+
+/// Summarize a single date without the zmanim block
+async fn day_summary(
+    state: State<Arc<ApiState>>,
+    params: Query<ConvertRequest>,
+) -> Result<Json<DaySummary>, ApiError> {
+    let Json(data) = convert_date(state, params).await?;
+    
+    let is_shabbat = data.hebrew.day_of_week() == 6;
+    let primary_label = match data.holidays.first() {
+        Some(holiday) => Some(holiday.name().to_string()),
+        None if is_shabbat => Some("Shabbat".to_string()),
+        None => None,
+    };
+    
+    Ok(Json(DaySummary {
+        hebrew_date: data.hebrew.format(),
+        is_shabbat,
+        is_yom_tov: data.holidays.iter().any(Holiday::is_yom_tov),
+        is_fast: data.holidays.iter().any(Holiday::is_fast_day),
+        is_rosh_chodesh: data.holidays.contains(&Holiday::RoshChodesh),
+        primary_label,
+        candle_lighting: data.candle_lighting,
+    }))
+}
+
 /// Date range request parameters
 #[derive(Deserialize)]
 pub struct RangeRequest {
@@ -149,23 +213,15 @@ async fn date_range(
     let end = HebrewCalendar::parse_date(&params.end)
         .map_err(ApiError::from)?;
     
-    if end < start {
-        return Err(ApiError::BadRequest("End date must be after start date".to_string()));
-    }
-    
     // Limit range to prevent abuse
-    let days = (end - start).num_days();
-    if days > 366 {
-        return Err(ApiError::BadRequest(
-            format!("Date range too large (max 366 days, requested {})", days)
-        ));
-    }
+    let days = HebrewCalendar::validate_range(start, end, HebrewCalendar::MAX_RANGE_DAYS)
+        .map_err(ApiError::from)?;
     
     let location = if let (Some(lat), Some(long)) = (params.lat, params.long) {
         let mut loc = hebrew_core::zmanim::GeoLocation::new(lat, long)
             .map_err(ApiError::from)?;
         if let Some(elev) = params.elevation {
-            loc = loc.with_elevation(elev);
+            loc = loc.with_elevation(elev).map_err(ApiError::from)?;
         }
         Some(loc)
     } else {
@@ -175,11 +231,11 @@ async fn date_range(
     let candle_offset = params.candle_offset
         .unwrap_or(state.config.candle_lighting_offset_minutes);
     
-    let mut results = Vec::with_capacity(days as usize + 1);
+    let mut results = Vec::with_capacity(days as usize);
     let mut current = start;
     
     while current <= end {
-        let data = HebrewCalendar::calculate_day(current, location.clone(), candle_offset)
+        let data = HebrewCalendar::calculate_day(current, CalendarSystem::Gregorian, location.clone(), candle_offset)
             .map_err(ApiError::from)?;
         results.push(data);
         current = current.succ_opt().unwrap();
@@ -188,6 +244,267 @@ async fn date_range(
     Ok(Json(results))
 }
 
+/// Year information request parameters (exactly one of the two)
+#[derive(Deserialize)]
+pub struct YearInfoRequest {
+    hebrew_year: Option<i32>,
+    gregorian_year: Option<i32>,
+}
+
+/// Get information about a Hebrew year
+///
+/// Returns one entry for `hebrew_year`, or the two Hebrew years
+/// overlapping `gregorian_year`.
+async fn year_info(
+    Query(params): Query<YearInfoRequest>,
+) -> Result<Json<Vec<HebrewYearInfo>>, ApiError> {
+    let years = match (params.hebrew_year, params.gregorian_year) {
+        (Some(year), None) => vec![year],
+        (None, Some(gregorian_year)) => {
+            let (first, second) = DateConverter::hebrew_years_overlapping(gregorian_year)
+                .map_err(ApiError::from)?;
+            vec![first, second]
+        }
+        _ => {
+            return Err(ApiError::from(CalendarError::InvalidDateFormat(
+                "Provide exactly one of hebrew_year or gregorian_year".to_string()
+            )));
+        }
+    };
+    
+    let infos = years.into_iter()
+        .map(DateConverter::year_info)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(ApiError::from)?;
+    
+    Ok(Json(infos))
+}
+
+/// Calendar feed request parameters
+#[derive(Deserialize)]
+pub struct FeedRequest {
+    /// Location (default from config)
+    lat: Option<f64>,
+    long: Option<f64>,
+    elevation: Option<f64>,
+    /// IANA time zone, e.g. America/New_York (default UTC)
+    tz: Option<String>,
+    /// How many weeks from today to cover (default 52)
+    weeks: Option<u32>,
+    /// Candle lighting offset in minutes (default from config)
+    candle_offset: Option<i64>,
+    /// Minutes after sunset for Havdalah (default 42)
+    havdalah_offset: Option<i64>,
+    /// Follow the Israeli festival calendar (default: only for the Israeli time zone)
+    israel: Option<bool>,
+}
+
+/// Longest window a calendar feed may cover
+const MAX_FEED_WEEKS: u32 = 104;
+
+/// How often subscribers should re-fetch the feed
+const FEED_REFRESH_SECONDS: u32 = 24 * 60 * 60;
+
+/// IANA names of the time zone kept in Israel
+const ISRAEL_TIME_ZONES: &[&str] = &["Asia/Jerusalem", "Asia/Tel_Aviv", "Israel"];
+
+/// The festival calendar kept at a location
+///
+/// Only a location in the Israeli time zone defaults to the Israeli
+/// calendar; anywhere else it takes `?israel=true`.
+fn scheme_for(location: &GeoLocation) -> HolidayScheme {
+    match location.timezone_id.as_deref() {
+        Some(zone) if ISRAEL_TIME_ZONES.contains(&zone) => HolidayScheme::Israel,
+        _ => HolidayScheme::Diaspora,
+    }
+}
+
+/// Rolling iCalendar feed of candle lighting, Havdalah and holidays
+///
+/// The window starts today at the location, so a subscription URL keeps
+/// moving forward as clients refresh it.
+async fn calendar_feed(
+    State(state): State<Arc<ApiState>>,
+    Query(params): Query<FeedRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let mut location = match (params.lat, params.long) {
+        (Some(lat), Some(long)) => GeoLocation::new(lat, long).map_err(ApiError::from)?,
+        _ => state.config.default_location.clone(),
+    };
+    if let Some(elev) = params.elevation {
+        location = location.with_elevation(elev).map_err(ApiError::from)?;
+    }
+    if let Some(tz) = &params.tz {
+        location = location.with_timezone_id(tz).map_err(ApiError::from)?;
+    }
+    location.validate().map_err(ApiError::from)?;
+    let scheme = match params.israel {
+        Some(true) => HolidayScheme::Israel,
+        Some(false) => HolidayScheme::Diaspora,
+        None => scheme_for(&location),
+    };
+    
+    let now = Utc::now();
+    let today = now.with_timezone(&location.utc_offset_at(now.naive_utc())).date_naive();
+    
+    let weeks = params.weeks.unwrap_or(52).max(1);
+    let end = today + Duration::weeks(weeks as i64) - Duration::days(1);
+    HebrewCalendar::validate_range(today, end, MAX_FEED_WEEKS as i64 * 7)
+        .map_err(ApiError::from)?;
+    
+    let candle_offset = params.candle_offset
+        .unwrap_or(state.config.candle_lighting_offset_minutes);
+    let body = build_feed(&location, today, end, candle_offset, params.havdalah_offset.unwrap_or(42), scheme)
+        .map_err(ApiError::from)?;
+    
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/calendar; charset=utf-8".to_string()),
+            (header::CACHE_CONTROL, format!("public, max-age={}", FEED_REFRESH_SECONDS)),
+        ],
+        body,
+    ))
+}
+
+/// Start of a feed event: all-day or at an instant
+enum EventStart {
+    Day(NaiveDate),
+    At(DateTime<Utc>),
+}
+
+/// Write the VCALENDAR for `start` through `end` (inclusive)
+fn build_feed(
+    location: &GeoLocation,
+    start: NaiveDate,
+    end: NaiveDate,
+    candle_offset: i64,
+    havdalah_offset: i64,
+    scheme: HolidayScheme,
+) -> Result<String, CalendarError> {
+    let name = match &location.location_name {
+        Some(name) => format!("Hebrew Calendar - {}", name),
+        None => format!("Hebrew Calendar - {:.4}, {:.4}", location.latitude, location.longitude),
+    };
+    let stamp = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+    // Local times get the offset in force at that moment, so DST is respected
+    let instant = |local: NaiveDateTime| -> Result<DateTime<Utc>, CalendarError> {
+        location.utc_offset_at(local).from_local_datetime(&local).single()
+            .map(|t| t.with_timezone(&Utc))
+            .ok_or_else(|| CalendarError::CalculationError(format!("no local time {}", local)))
+    };
+    let calc = ZmanimCalculator::new(location.clone());
+    let sunset_of = |date: NaiveDate| -> Result<Option<NaiveDateTime>, CalendarError> {
+        Ok(calc.calculate(date)?.as_datetimes().sunset)
+    };
+    
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:-//hebrew-calendar//hebrew_app {}//EN", env!("CARGO_PKG_VERSION")),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        format!("X-WR-CALNAME:{}", ical_escape(&name)),
+        format!("REFRESH-INTERVAL;VALUE=DURATION:PT{}S", FEED_REFRESH_SECONDS),
+        format!("X-PUBLISHED-TTL:PT{}S", FEED_REFRESH_SECONDS),
+    ];
+    let mut event = |uid: String, start: EventStart, summary: &str| {
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}@hebrew-calendar", uid));
+        lines.push(format!("DTSTAMP:{}", stamp));
+        match start {
+            EventStart::Day(date) => {
+                lines.push(format!("DTSTART;VALUE=DATE:{}", date.format("%Y%m%d")));
+                lines.push(format!("DTEND;VALUE=DATE:{}", (date + Duration::days(1)).format("%Y%m%d")));
+                lines.push("TRANSP:TRANSPARENT".to_string());
+            }
+            EventStart::At(time) => {
+                lines.push(format!("DTSTART:{}", time.format("%Y%m%dT%H%M%SZ")));
+            }
+        }
+        lines.push(format!("SUMMARY:{}", ical_escape(summary)));
+        lines.push("END:VEVENT".to_string());
+    };
+    
+    // Candle lighting, which may span two civil years
+    for year in start.year()..=end.year() {
+        for (date, time, label) in HebrewCalendar::candle_lighting_calendar(year, location, candle_offset, scheme)? {
+            if !(start..=end).contains(&date) {
+                continue;
+            }
+            // The wall-clock time belongs to the evening around that day's sunset,
+            // which may fall on the next civil day away from the zone's meridian
+            let Some(sunset) = sunset_of(date)? else { continue };
+            let time = NaiveTime::parse_from_str(&time, "%H:%M")
+                .map_err(|e| CalendarError::CalculationError(e.to_string()))?;
+            let lit = nearest_at(sunset, time);
+            let uid = format!("{}-candles", date.format("%Y%m%d"));
+            event(uid, EventStart::At(instant(lit)?), &format!("Candle lighting: {}", label));
+        }
+    }
+    
+    // Holidays, and Havdalah wherever Shabbat or Yom Tov ends
+    let holidays_on = |date: NaiveDate| {
+        HolidayCalculator::get_holidays_for_scheme(&DateConverter::gregorian_to_hebrew(date)?, scheme)
+    };
+    let is_holy = |date: NaiveDate, holidays: &[Holiday]| {
+        date.weekday() == Weekday::Sat || holidays.iter().any(Holiday::is_yom_tov)
+    };
+    let mut holidays = holidays_on(start)?;
+    for date in start.iter_days().take_while(|date| *date <= end) {
+        let next = date + Duration::days(1);
+        let next_holidays = holidays_on(next)?;
+        
+        for holiday in &holidays {
+            let uid = format!("{}-{:?}", date.format("%Y%m%d"), holiday);
+            event(uid, EventStart::Day(date), holiday.name());
+        }
+        if is_holy(date, &holidays) && !is_holy(next, &next_holidays) {
+            // Minutes after sunset are elapsed time, even across a DST change
+            if let Some(sunset) = sunset_of(date)? {
+                let time = instant(sunset)? + Duration::minutes(havdalah_offset);
+                event(format!("{}-havdalah", date.format("%Y%m%d")), EventStart::At(time), "Havdalah");
+            }
+        }
+        
+        holidays = next_holidays;
+    }
+    
+    lines.push("END:VCALENDAR".to_string());
+    Ok(lines.iter().map(|line| ical_fold(line)).collect())
+}
+
+/// `time` on whichever of the days around `anchor` lands closest to it
+fn nearest_at(anchor: NaiveDateTime, time: NaiveTime) -> NaiveDateTime {
+    [-1, 0, 1].into_iter()
+        .map(|days| (anchor.date() + Duration::days(days)).and_time(time))
+        .min_by_key(|candidate| (*candidate - anchor).num_seconds().abs())
+        .unwrap_or(anchor)
+}
+
+/// Escape an iCalendar TEXT value
+fn ical_escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Fold a content line at 75 octets and terminate it with CRLF
+fn ical_fold(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + 2);
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            width = 1;
+        }
+        folded.push(c);
+        width += c.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
+
 /// Zmanim request parameters
 #[derive(Deserialize)]
 pub struct ZmanimRequest {
@@ -195,58 +512,133 @@ pub struct ZmanimRequest {
     lat: f64,
     long: f64,
     elevation: Option<f64>,
+    /// IANA time zone, e.g. America/New_York (default UTC)
+    tz: Option<String>,
 }
 
-/// Get zmanim for a date
-async fn get_zmanim(
-    Query(params): Query<ZmanimRequest>,
-) -> Result<Json<hebrew_core::zmanim::Zmanim>, ApiError> {
+/// Calculate zmanim for a request (shared by every API version)
+fn compute_zmanim(params: &ZmanimRequest) -> Result<Zmanim, ApiError> {
     let date = HebrewCalendar::parse_date(&params.date)
         .map_err(ApiError::from)?;
     
     let mut loc = hebrew_core::zmanim::GeoLocation::new(params.lat, params.long)
         .map_err(ApiError::from)?;
     if let Some(elev) = params.elevation {
-        loc = loc.with_elevation(elev);
+        loc = loc.with_elevation(elev).map_err(ApiError::from)?;
+    }
+    if let Some(tz) = &params.tz {
+        loc = loc.with_timezone_id(tz).map_err(ApiError::from)?;
     }
     
     let calc = hebrew_core::zmanim::ZmanimCalculator::new(loc);
-    let zmanim = calc.calculate(date)
-        .map_err(ApiError::from)?;
-    
-    Ok(Json(zmanim))
+    calc.calculate(date).map_err(ApiError::from)
+}
+
+/// Get zmanim for a date
+async fn get_zmanim(
+    Query(params): Query<ZmanimRequest>,
+) -> Result<Json<Zmanim>, ApiError> {
+    Ok(Json(compute_zmanim(&params)?))
+}
+
+/// Version 2 zmanim: camelCase keys, each time an RFC 3339 timestamp
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZmanimV2 {
+    date: String,
+    latitude: f64,
+    longitude: f64,
+    alot_hashachar: Option<String>,
+    misheyakir: Option<String>,
+    sunrise: Option<String>,
+    sof_zman_shema_mga: Option<String>,
+    sof_zman_shema_gra: Option<String>,
+    sof_zman_tefila_mga: Option<String>,
+    sof_zman_tefila_gra: Option<String>,
+    chatzot: Option<String>,
+    mincha_gedola: Option<String>,
+    mincha_ketana: Option<String>,
+    plag_hamincha: Option<String>,
+    sunset: Option<String>,
+    tzeit_hakochavim: Option<String>,
+    tzeit_72_min: Option<String>,
+}
+
+impl From<Zmanim> for ZmanimV2 {
+    fn from(zmanim: Zmanim) -> Self {
+        // Each time gets the offset in force at that moment, so DST is respected
+        let location = &zmanim.location;
+        let rfc3339 = |time: Option<NaiveDateTime>| {
+            time.and_then(|t| location.utc_offset_at(t).from_local_datetime(&t).single())
+                .map(|t| t.to_rfc3339())
+        };
+        let times = zmanim.as_datetimes();
+        
+        ZmanimV2 {
+            date: zmanim.date,
+            latitude: zmanim.location.latitude,
+            longitude: zmanim.location.longitude,
+            alot_hashachar: rfc3339(times.alot_hashachar),
+            misheyakir: rfc3339(times.misheyakir),
+            sunrise: rfc3339(times.sunrise),
+            sof_zman_shema_mga: rfc3339(times.sof_zman_shema_mga),
+            sof_zman_shema_gra: rfc3339(times.sof_zman_shema_gra),
+            sof_zman_tefila_mga: rfc3339(times.sof_zman_tefila_mga),
+            sof_zman_tefila_gra: rfc3339(times.sof_zman_tefila_gra),
+            chatzot: rfc3339(times.chatzot),
+            mincha_gedola: rfc3339(times.mincha_gedola),
+            mincha_ketana: rfc3339(times.mincha_ketana),
+            plag_hamincha: rfc3339(times.plag_hamincha),
+            sunset: rfc3339(times.sunset),
+            tzeit_hakochavim: rfc3339(times.tzeit_hakochavim),
+            tzeit_72_min: rfc3339(times.tzeit_72_min),
+        }
+    }
+}
+
+/// Get zmanim for a date (version 2 shape)
+async fn get_zmanim_v2(
+    Query(params): Query<ZmanimRequest>,
+) -> Result<Json<ZmanimV2>, ApiError> {
+    Ok(Json(compute_zmanim(&params)?.into()))
 }
 
 /// Upcoming holidays request
 #[derive(Deserialize)]
 pub struct HolidaysRequest {
+    /// Gregorian year to list (default: the rest of the current year)
     year: Option<i32>,
 }
 
-/// Get upcoming holidays for a year
+/// List the holidays of a Gregorian year (diaspora calendar)
+///
+/// Without `year`, lists the holidays from today through December 31.
 async fn upcoming_holidays(
     Query(params): Query<HolidaysRequest>,
 ) -> Result<Json<Vec<HolidayInfo>>, ApiError> {
-    use chrono::NaiveDate;
-    use hebrew_core::calendar::{DateConverter, HebrewDate, HebrewMonth};
-    use hebrew_core::holidays::{Holiday, HolidayCalculator};
-    
-    let year = params.year.unwrap_or_else(|| {
-        chrono::Local::now().year()
-    });
-    
-    let mut holidays = Vec::new();
-    
-    // Get holidays for the entire Hebrew year
-    // Find Rosh Hashanah of the Gregorian year
-    let rosh_hashanah_gregorian = if year >= 1 {
-        NaiveDate::from_ymd_opt(year, 9, 1).unwrap() // Approximate
-    } else {
-        NaiveDate::from_ymd_opt(0, 9, 1).unwrap()
+    let today = chrono::Local::now().date_naive();
+    let year = params.year.unwrap_or(today.year());
+    let start = match params.year {
+        Some(_) => NaiveDate::from_ymd_opt(year, 1, 1),
+        None => Some(today),
+    };
+    let (Some(start), Some(end)) = (start, NaiveDate::from_ymd_opt(year, 12, 31)) else {
+        return Err(ApiError::from(CalendarError::InvalidDateFormat(format!("Invalid year {}", year))));
     };
     
-    // This is a simplified implementation
-    // A full implementation would iterate through the Hebrew year
+    let holidays = HolidayCalculator::holidays_between(start, end, HolidayScheme::Diaspora)
+        .map_err(ApiError::from)?
+        .into_iter()
+        .map(|(date, holiday)| {
+            Ok(HolidayInfo {
+                name: holiday.name().to_string(),
+                hebrew_date: DateConverter::gregorian_to_hebrew(date)?.format(),
+                gregorian_date: date.to_string(),
+                is_yom_tov: holiday.is_yom_tov(),
+            })
+        })
+        .collect::<Result<Vec<_>, CalendarError>>()
+        .map_err(ApiError::from)?;
     
     Ok(Json(holidays))
 }
@@ -262,7 +654,6 @@ pub struct HolidayInfo {
 /// API error type
 #[derive(Debug)]
 pub enum ApiError {
-    BadRequest(String),
     Calendar(CalendarError),
 }
 
@@ -274,29 +665,23 @@ impl From<CalendarError> for ApiError {
 
 impl axum::response::IntoResponse for ApiError {
     fn into_response(self) -> axum::response::Response {
-        let (status, message) = match self {
-            ApiError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
+        let (status, body) = match self {
             ApiError::Calendar(err) => {
-                let msg = err.to_string();
-                let status = match err {
-                    CalendarError::DateOutOfRange(_) => StatusCode::BAD_REQUEST,
-                    CalendarError::InvalidDateFormat(_) => StatusCode::BAD_REQUEST,
-                    CalendarError::InvalidLatitude(_) => StatusCode::BAD_REQUEST,
-                    CalendarError::InvalidLongitude(_) => StatusCode::BAD_REQUEST,
-                    CalendarError::CalculationError(_) => StatusCode::INTERNAL_SERVER_ERROR,
-                };
-                (status, msg)
+                let status = StatusCode::from_u16(err.http_status())
+                    .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+                (status, ErrorResponse { error: err.to_string(), code: err.code() })
             }
         };
         
-        let body = Json(ErrorResponse { error: message });
-        (status, body).into_response()
+        (status, Json(body)).into_response()
     }
 }
 
 #[derive(Serialize)]
 struct ErrorResponse {
     error: String,
+    /// Stable code from `CalendarError::code`
+    code: &'static str,
 }
 
 #[cfg(test)]
@@ -305,6 +690,7 @@ mod tests {
     use axum::body::Body;
     use axum::http::{Request, StatusCode as HttpStatusCode};
     use tower::ServiceExt;
+    use chrono::FixedOffset;
 
     fn test_app() -> Router {
         build_router(AppConfig::default())
@@ -409,6 +795,176 @@ mod tests {
             .await
             .unwrap();
         assert_eq!(response.status(), HttpStatusCode::BAD_REQUEST);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["code"], "RANGE_TOO_LARGE");
+    }
+
+    #[tokio::test]
+    async fn test_date_range_reversed() {
+        let app = test_app();
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/calendar/range?start=2024-02-01&end=2024-01-01")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), HttpStatusCode::BAD_REQUEST);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["code"], "INVALID_RANGE");
+    }
+
+    #[tokio::test]
+    async fn test_calendar_feed() {
+        let app = test_app();
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/calendar/feed.ics?lat=31.77&long=35.21&weeks=52")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), HttpStatusCode::OK);
+        assert_eq!(response.headers()["content-type"], "text/calendar; charset=utf-8");
+        assert!(response.headers()["cache-control"].to_str().unwrap().contains("max-age=86400"));
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let ics = String::from_utf8(body.to_vec()).unwrap();
+        
+        // Well-formed: CRLF lines of at most 75 octets, balanced components
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert!(ics.split("\r\n").all(|line| !line.contains('\n') && line.len() <= 75));
+        assert!(ics.contains("\r\nX-WR-CALNAME:Hebrew Calendar - 31.7700\\, 35.2100\r\n"));
+        assert!(ics.contains("\r\nREFRESH-INTERVAL;VALUE=DURATION:PT86400S\r\n"));
+        let events = ics.matches("BEGIN:VEVENT").count();
+        assert_eq!(events, ics.matches("END:VEVENT").count());
+        assert_eq!(events, ics.matches("\r\nUID:").count());
+        
+        // Roughly 52 Shabbatot of candles and Havdalah, plus the holidays
+        assert!(ics.matches("SUMMARY:Candle lighting").count() >= 52);
+        assert!(ics.matches("SUMMARY:Havdalah").count() >= 50);
+        assert!(ics.contains("SUMMARY:Yom Kippur"));
+        
+        let dates: Vec<NaiveDate> = ics.lines()
+            .filter_map(|line| line.strip_prefix("DTSTART"))
+            .map(|value| {
+                let value = value.trim_end().rsplit(':').next().unwrap();
+                NaiveDate::parse_from_str(&value[..8], "%Y%m%d").unwrap()
+            })
+            .collect();
+        let first = *dates.iter().min().unwrap();
+        let last = *dates.iter().max().unwrap();
+        assert!((Utc::now().date_naive() - first).num_days().abs() <= 7);
+        assert!((350..364).contains(&(last - first).num_days()), "{} to {}", first, last);
+    }
+
+    #[tokio::test]
+    async fn test_calendar_feed_too_long() {
+        let app = test_app();
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/calendar/feed.ics?weeks=500")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), HttpStatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_feed_times_in_western_hemisphere() {
+        // New York, June 2024: candles Friday 7th at 20:07 EDT, Havdalah Saturday 15th at 21:11 EDT
+        let start = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 6, 30).unwrap();
+        let utc = GeoLocation::new(40.7128, -74.0060).unwrap();
+        let zoned = utc.clone().with_timezone_id("America/New_York").unwrap();
+        for location in [utc, zoned] {
+            let ics = build_feed(&location, start, end, 18, 42, HolidayScheme::Diaspora).unwrap();
+            let start_of = |uid: &str| {
+                let event = &ics[ics.find(&format!("UID:{}@", uid)).unwrap()..];
+                let dtstart = event.lines().find_map(|line| line.strip_prefix("DTSTART:")).unwrap();
+                DateTime::parse_from_str(&format!("{} +0000", dtstart.trim_end()), "%Y%m%dT%H%M%SZ %z").unwrap()
+            };
+            let edt = FixedOffset::west_opt(4 * 3600).unwrap();
+            let candles = start_of("20240607-candles").with_timezone(&edt);
+            assert_eq!(candles.weekday(), Weekday::Fri);
+            assert_eq!(candles.format("%H:%M").to_string(), "20:07");
+            let havdalah = start_of("20240615-havdalah").with_timezone(&edt);
+            assert_eq!(havdalah.weekday(), Weekday::Sat);
+            assert_eq!(havdalah.format("%H:%M").to_string(), "21:11");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_calendar_feed_scheme() {
+        async fn feed(query: &str) -> (HttpStatusCode, String) {
+            let response = test_app()
+                .oneshot(
+                    Request::builder()
+                        .uri(format!("/api/v1/calendar/feed.ics?weeks=52&{}", query))
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            let status = response.status();
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            (status, String::from_utf8(body.to_vec()).unwrap())
+        }
+        
+        // A year always holds one Shavuot: one day in Israel, two abroad
+        let (status, jerusalem) = feed("lat=31.77&long=35.21&tz=Asia/Jerusalem").await;
+        assert_eq!(status, HttpStatusCode::OK);
+        assert!(jerusalem.contains("SUMMARY:Shavuot (Day 1)"));
+        assert!(!jerusalem.contains("SUMMARY:Shavuot (Day 2)"));
+        let (_, new_york) = feed("lat=40.71&long=-74.01&tz=America/New_York").await;
+        assert!(new_york.contains("SUMMARY:Shavuot (Day 2)"));
+        
+        // The zone decides, not the distance to a known city
+        let (_, eilat) = feed("lat=29.56&long=34.95&tz=Asia/Jerusalem").await;
+        assert!(!eilat.contains("SUMMARY:Shavuot (Day 2)"));
+        let (_, amman) = feed("lat=31.95&long=35.93&tz=Asia/Amman").await;
+        assert!(amman.contains("SUMMARY:Shavuot (Day 2)"));
+        
+        // Without a zone the feed is diaspora; an explicit parameter overrides either way
+        let (_, unzoned) = feed("lat=31.77&long=35.21").await;
+        assert!(unzoned.contains("SUMMARY:Shavuot (Day 2)"));
+        let (_, abroad) = feed("lat=31.77&long=35.21&tz=Asia/Jerusalem&israel=false").await;
+        assert!(abroad.contains("SUMMARY:Shavuot (Day 2)"));
+        let (_, visiting) = feed("lat=40.71&long=-74.01&israel=true").await;
+        assert!(!visiting.contains("SUMMARY:Shavuot (Day 2)"));
+        
+        let (status, _) = feed("lat=31.77&long=35.21&elevation=-1000").await;
+        assert_eq!(status, HttpStatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_upcoming_holidays_for_year() {
+        let app = test_app();
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/holidays/upcoming?year=2024")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), HttpStatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let data: Vec<serde_json::Value> = serde_json::from_slice(&body).unwrap();
+        let yom_kippur = data.iter().find(|h| h["name"] == "Yom Kippur").unwrap();
+        assert_eq!(yom_kippur["gregorian_date"], "2024-10-12");
+        assert_eq!(yom_kippur["is_yom_tov"], true);
+        assert!(data.iter().all(|h| h["gregorian_date"].as_str().unwrap().starts_with("2024-")));
     }
 
     #[tokio::test]
@@ -429,4 +985,136 @@ mod tests {
         assert!(data.get("sunrise").is_some());
         assert!(data.get("sunset").is_some());
     }
+
+    #[tokio::test]
+    async fn test_zmanim_v1_and_v2_shapes() {
+        async fn fetch(uri: &str) -> serde_json::Value {
+            let response = test_app()
+                .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), HttpStatusCode::OK);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            serde_json::from_slice(&body).unwrap()
+        }
+        let query = "zmanim?date=2024-06-15&lat=31.77&long=35.21";
+        let v1 = fetch(&format!("/api/v1/{}", query)).await;
+        let v2 = fetch(&format!("/api/v2/{}", query)).await;
+        
+        // v1: snake_case, "HH:MM"; v2: camelCase, RFC 3339 with the offset
+        let v1_shema = v1["sof_zman_shema_gra"].as_str().unwrap();
+        let v2_shema = v2["sofZmanShemaGra"].as_str().unwrap();
+        assert!(v1.get("sofZmanShemaGra").is_none());
+        assert!(v2.get("sof_zman_shema_gra").is_none());
+        assert_eq!(v1_shema.len(), 5);
+        assert_eq!(v2_shema, format!("2024-06-15T{}:00+00:00", v1_shema));
+        
+        let sunset = chrono::DateTime::parse_from_rfc3339(v2["sunset"].as_str().unwrap()).unwrap();
+        assert_eq!(sunset.format("%H:%M").to_string(), v1["sunset"].as_str().unwrap());
+        
+        // With an IANA zone the offset follows DST
+        let zoned = |date| format!("/api/v2/zmanim?date={}&lat=40.71&long=-74.01&tz=America/New_York", date);
+        let summer = fetch(&zoned("2024-06-15")).await;
+        let winter = fetch(&zoned("2024-01-15")).await;
+        assert!(summer["sunrise"].as_str().unwrap().ends_with("-04:00"));
+        assert!(winter["sunrise"].as_str().unwrap().ends_with("-05:00"));
+    }
+
+    #[tokio::test]
+    async fn test_year_info_hebrew_year() {
+        let app = test_app();
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/calendar/year-info?hebrew_year=5784")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), HttpStatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let data: Vec<serde_json::Value> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(data.len(), 1);
+        assert_eq!(data[0]["is_leap"], true);
+        assert_eq!(data[0]["length"], 383);
+        assert_eq!(data[0]["year_type"], "deficient_leap");
+    }
+
+    #[tokio::test]
+    async fn test_year_info_gregorian_year() {
+        let app = test_app();
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/calendar/year-info?gregorian_year=2024")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), HttpStatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let data: Vec<serde_json::Value> = serde_json::from_slice(&body).unwrap();
+        let years: Vec<_> = data.iter().map(|info| info["year"].as_i64().unwrap()).collect();
+        assert_eq!(years, vec![5784, 5785]);
+    }
+
+    #[tokio::test]
+    async fn test_year_info_requires_one_year() {
+        let app = test_app();
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/calendar/year-info")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), HttpStatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_summary_shabbat() {
+        let app = test_app();
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/calendar/summary?date=2024-01-06&lat=31.77&long=35.21")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), HttpStatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let data: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(data["is_shabbat"], true);
+        assert_eq!(data["is_yom_tov"], false);
+        assert_eq!(data["primary_label"], "Shabbat");
+        assert!(data.get("zmanim").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_summary_weekday() {
+        let app = test_app();
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/calendar/summary?date=2024-01-03")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), HttpStatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let data: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(data["is_shabbat"], false);
+        assert_eq!(data["is_fast"], false);
+        assert_eq!(data["is_rosh_chodesh"], false);
+        assert!(data["primary_label"].is_null());
+        assert!(data["candle_lighting"].is_null());
+    }
 }
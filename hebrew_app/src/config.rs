@@ -5,7 +5,7 @@
 use hebrew_core::zmanim::GeoLocation;
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Application configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -60,30 +60,31 @@ impl Default for ApiSettings {
 impl AppConfig {
     /// Load configuration from file or create default
     pub fn load() -> anyhow::Result<Self> {
-        let config_path = Self::config_path()?;
-        
-        if config_path.exists() {
-            let contents = fs::read_to_string(&config_path)?;
+        Self::load_from(&Self::config_path()?)
+    }
+    
+    /// Load configuration from a specific file, creating it with defaults if missing
+    pub fn load_from(path: &Path) -> anyhow::Result<Self> {
+        if path.exists() {
+            let contents = fs::read_to_string(path)?;
             let config: AppConfig = serde_json::from_str(&contents)?;
             Ok(config)
         } else {
             let config = Self::default();
-            config.save()?;
+            config.save_to(path)?;
             Ok(config)
         }
     }
     
-    /// Save configuration to file
-    pub fn save(&self) -> anyhow::Result<()> {
-        let config_path = Self::config_path()?;
-        
+    /// Save configuration to a specific file
+    pub fn save_to(&self, path: &Path) -> anyhow::Result<()> {
         // Create config directory if it doesn't exist
-        if let Some(parent) = config_path.parent() {
+        if let Some(parent) = path.parent() {
             fs::create_dir_all(parent)?;
         }
         
         let contents = serde_json::to_string_pretty(self)?;
-        fs::write(&config_path, contents)?;
+        fs::write(path, contents)?;
         
         Ok(())
     }
@@ -97,11 +98,13 @@ impl AppConfig {
     }
     
     /// Update the default location
+    #[cfg_attr(not(feature = "gui"), allow(dead_code))]
     pub fn set_location(&mut self, location: GeoLocation) {
         self.default_location = location;
     }
     
     /// Update candle lighting offset
+    #[cfg_attr(not(feature = "gui"), allow(dead_code))]
     pub fn set_candle_offset(&mut self, minutes: i64) {
         self.candle_lighting_offset_minutes = minutes;
     }
@@ -145,6 +148,26 @@ mod tests {
         assert_eq!(config.candle_lighting_offset_minutes, 40);
     }
 
+    #[test]
+    fn test_load_from_path() {
+        let dir = std::env::temp_dir().join(format!("hebrew-calendar-test-{}", std::process::id()));
+        let path = dir.join("config.json");
+        
+        // A missing file is created with defaults
+        let created = AppConfig::load_from(&path).unwrap();
+        assert!(path.exists());
+        assert_eq!(created.candle_lighting_offset_minutes, 18);
+        
+        let mut config = AppConfig::default();
+        config.set_candle_offset(40);
+        config.save_to(&path).unwrap();
+        assert_eq!(AppConfig::load_from(&path).unwrap().candle_lighting_offset_minutes, 40);
+        
+        fs::write(&path, "not json").unwrap();
+        assert!(AppConfig::load_from(&path).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
     #[test]
     fn test_default_api_settings() {
         let settings = ApiSettings::default();
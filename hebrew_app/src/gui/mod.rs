@@ -2,21 +2,25 @@
 //! 
 //! Tauri-based desktop GUI for the Hebrew calendar application.
 
-use hebrew_core::{DailyData, HebrewCalendar};
+use hebrew_core::{CalendarSystem, DailyData, HebrewCalendar};
 use tauri::{Manager, State};
 
 use crate::config::AppConfig;
+use std::path::PathBuf;
 use std::sync::Mutex;
 
 /// Application state managed by Tauri
 pub struct AppState {
     pub config: Mutex<AppConfig>,
+    /// File the configuration was loaded from and is saved back to
+    pub config_path: PathBuf,
 }
 
 /// Launch the Tauri GUI
-pub fn launch(config: AppConfig) -> anyhow::Result<()> {
+pub fn launch(config: AppConfig, config_path: PathBuf) -> anyhow::Result<()> {
     let state = AppState {
         config: Mutex::new(config),
+        config_path,
     };
 
     tauri::Builder::default()
@@ -60,13 +64,13 @@ fn get_calendar_data(
     let location = if let (Some(lat), Some(long)) = (lat, long) {
         let mut loc = hebrew_core::zmanim::GeoLocation::new(lat, long)
             .map_err(|e| e.to_string())?;
-        loc = loc.with_timezone(0); // UTC for now
+        loc = loc.with_timezone(0).map_err(|e| e.to_string())?; // UTC for now
         Some(loc)
     } else {
         Some(config.default_location.clone())
     };
     
-    HebrewCalendar::calculate_day(date, location, config.candle_lighting_offset_minutes)
+    HebrewCalendar::calculate_day(date, CalendarSystem::Gregorian, location, config.candle_lighting_offset_minutes)
         .map_err(|e| e.to_string())
 }
 
@@ -85,7 +89,7 @@ fn get_zmanim(
         .map_err(|e| e.to_string())?;
     
     if let Some(elev) = elevation {
-        loc = loc.with_elevation(elev);
+        loc = loc.with_elevation(elev).map_err(|e| e.to_string())?;
     }
     
     let calc = hebrew_core::zmanim::ZmanimCalculator::new(loc);
@@ -112,31 +116,26 @@ fn get_date_range(
     let end = HebrewCalendar::parse_date(&end_str)
         .map_err(|e| e.to_string())?;
     
-    if end < start {
-        return Err("End date must be after start date".to_string());
-    }
-    
     // Limit range
-    let days = (end - start).num_days();
-    if days > 366 {
-        return Err("Date range too large (max 366 days)".to_string());
-    }
+    let days = HebrewCalendar::validate_range(start, end, HebrewCalendar::MAX_RANGE_DAYS)
+        .map_err(|e| e.to_string())?;
     
     let location = if let (Some(lat), Some(long)) = (lat, long) {
         let mut loc = hebrew_core::zmanim::GeoLocation::new(lat, long)
             .map_err(|e| e.to_string())?;
-        loc = loc.with_timezone(0);
+        loc = loc.with_timezone(0).map_err(|e| e.to_string())?;
         Some(loc)
     } else {
         Some(config.default_location.clone())
     };
     
-    let mut results = Vec::with_capacity(days as usize + 1);
+    let mut results = Vec::with_capacity(days as usize);
     let mut current = start;
     
     while current <= end {
         let data = HebrewCalendar::calculate_day(
             current, 
+            CalendarSystem::Gregorian, 
             location.clone(), 
             config.candle_lighting_offset_minutes
         )
@@ -167,20 +166,20 @@ fn update_config(
     let mut config = state.config.lock().map_err(|e| e.to_string())?;
     
     if let Some(offset) = candle_offset {
-        config.candle_lighting_offset_minutes = offset;
+        config.set_candle_offset(offset);
     }
     
     if let (Some(lat), Some(long)) = (lat, long) {
         let mut loc = hebrew_core::zmanim::GeoLocation::new(lat, long)
             .map_err(|e| e.to_string())?;
         if let Some(elev) = elevation {
-            loc = loc.with_elevation(elev);
+            loc = loc.with_elevation(elev).map_err(|e| e.to_string())?;
         }
-        config.default_location = loc;
+        config.set_location(loc);
     }
     
     // Save to disk
-    config.save().map_err(|e| e.to_string())?;
+    config.save_to(&state.config_path).map_err(|e| e.to_string())?;
     
     Ok(())
 }
@@ -8,6 +8,9 @@
 //!   hebrew_app              # Run GUI mode (default)
 //!   hebrew_app --server     # Run API server mode
 //!   hebrew_app --server -p 8080  # Run API server on port 8080
+//!   hebrew_app --config ./config.json  # Use a specific config file
+
+use std::path::PathBuf;
 
 use clap::Parser;
 use tracing::info;
@@ -35,6 +38,10 @@ struct Args {
     /// Host for API server (only used with --server)
     #[arg(long, short = 'H', default_value = "0.0.0.0")]
     host: String,
+    
+    /// Config file to use instead of the one in the OS config directory
+    #[arg(long, value_name = "PATH")]
+    config: Option<PathBuf>,
 }
 
 #[tokio::main]
@@ -48,8 +55,15 @@ async fn main() -> anyhow::Result<()> {
     let args = Args::parse();
     
     // Load configuration
-    let config = config::AppConfig::load()?;
-    info!("Configuration loaded from {:?}", config::AppConfig::config_path()?);
+    let config = match &args.config {
+        Some(path) => config::AppConfig::load_from(path)?,
+        None => config::AppConfig::load()?,
+    };
+    let config_path = match args.config {
+        Some(path) => path,
+        None => config::AppConfig::config_path()?,
+    };
+    info!("Configuration loaded from {:?}", config_path);
     
     // Determine mode and launch
     if args.server {
@@ -68,7 +82,7 @@ async fn main() -> anyhow::Result<()> {
         #[cfg(feature = "gui")]
         {
             info!("🖥️  Starting in GUI mode");
-            gui::launch(config)?;
+            gui::launch(config, config_path)?;
         }
         
         #[cfg(not(feature = "gui"))]
@@ -5,13 +5,18 @@
 //! 
 //! Reference implementation: https://docs.rs/calendrical_calculations
 
-use chrono::{Datelike, NaiveDate};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
 use serde::{Deserialize, Serialize};
 
-use crate::CalendarError;
+use crate::gematria;
+use crate::{CalendarError, HebrewCalendar};
 
 /// Hebrew month enumeration
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[repr(u8)]
 pub enum HebrewMonth {
     Nisan = 1,
@@ -29,6 +34,18 @@ pub enum HebrewMonth {
     AdarI = 13,     // Adar I (in leap years only)
 }
 
+/// Transliteration convention for month names
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum TransliterationStyle {
+    /// Ashkenazi pronunciation, e.g. "Teves" (the `name()` default)
+    #[default]
+    Ashkenazi,
+    /// Sephardi / Modern Hebrew pronunciation, e.g. "Tevet"
+    Sephardi,
+    /// Academic transliteration, e.g. "Marheshvan"
+    Academic,
+}
+
 impl HebrewMonth {
     pub fn from_number(n: u8, is_leap: bool) -> Result<Self, CalendarError> {
         match (n, is_leap) {
@@ -47,7 +64,7 @@ impl HebrewMonth {
             (12, true) => Ok(HebrewMonth::AdarI),   // Month 12 = Adar I in leap years
             (13, true) => Ok(HebrewMonth::Adar),    // Month 13 = Adar II in leap years
             (13, false) => Err(CalendarError::CalculationError(
-                format!("Month 13 invalid in common year")
+                "Month 13 invalid in common year".to_string()
             )),
             _ => Err(CalendarError::CalculationError(
                 format!("Invalid Hebrew month number: {}", n)
@@ -73,6 +90,44 @@ impl HebrewMonth {
         }
     }
     
+    /// Month name in the given transliteration style
+    pub fn name_variant(&self, style: TransliterationStyle) -> &'static str {
+        match style {
+            TransliterationStyle::Ashkenazi => self.name(),
+            TransliterationStyle::Sephardi => match self {
+                HebrewMonth::Teves => "Tevet",
+                HebrewMonth::Shevat => "Shvat",
+                _ => self.name(),
+            },
+            TransliterationStyle::Academic => match self {
+                HebrewMonth::Tishrei => "Tishri",
+                HebrewMonth::Cheshvan => "Marheshvan",
+                HebrewMonth::Teves => "Tevet",
+                HebrewMonth::Iyar => "Iyyar",
+                _ => self.name(),
+            },
+        }
+    }
+    
+    /// Month name in Hebrew script
+    pub fn hebrew_name(&self) -> &'static str {
+        match self {
+            HebrewMonth::Tishrei => "תשרי",
+            HebrewMonth::Cheshvan => "חשון",
+            HebrewMonth::Kislev => "כסלו",
+            HebrewMonth::Teves => "טבת",
+            HebrewMonth::Shevat => "שבט",
+            HebrewMonth::Adar => "אדר",
+            HebrewMonth::AdarI => "אדר א׳",
+            HebrewMonth::Nisan => "ניסן",
+            HebrewMonth::Iyar => "אייר",
+            HebrewMonth::Sivan => "סיון",
+            HebrewMonth::Tammuz => "תמוז",
+            HebrewMonth::Av => "אב",
+            HebrewMonth::Elul => "אלול",
+        }
+    }
+    
     pub fn to_number(&self, is_leap: bool) -> u8 {
         match (self, is_leap) {
             (HebrewMonth::Nisan, _) => 1,
@@ -95,7 +150,7 @@ impl HebrewMonth {
 }
 
 /// Represents a Hebrew date
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct HebrewDate {
     pub year: i32,        // Hebrew year (e.g., 5784)
     pub month: HebrewMonth,
@@ -112,8 +167,27 @@ impl HebrewDate {
         format!("{} {} {}", self.day, self.month.name(), self.year)
     }
     
+    /// Format in Hebrew script with gematria numerals, e.g. "ט״ו בניסן תשפ״ד"
+    ///
+    /// The year is written without the thousands, except a round millennium,
+    /// which is spelled out ("ה׳ אלפים"); Adar is "אדר ב׳" in leap years.
+    pub fn format_hebrew(&self) -> String {
+        let month = match self.month {
+            HebrewMonth::Adar if DateConverter::is_hebrew_leap_year(self.year) => "אדר ב׳",
+            month => month.hebrew_name(),
+        };
+        let year = match self.year.rem_euclid(1000) {
+            0 => format!("{} אלפים", gematria::to_hebrew_number(self.year.div_euclid(1000) as u32)),
+            rest => gematria::to_hebrew_number(rest as u32),
+        };
+        format!("{} ב{} {}", gematria::to_hebrew_number(self.day as u32), month, year)
+    }
+    
     /// Get day of week (0 = Sunday, 1 = Monday, ..., 6 = Saturday)
     /// 
+    /// Returns 0 if the date cannot be converted; use `try_day_of_week` to
+    /// surface the error instead.
+    /// 
     /// Note: R.D. (Rata Die) day 0 = Saturday, December 30, year 0 (1 BCE)
     /// So R.D. % 7 gives: 0=Saturday, 1=Sunday, 2=Monday, ..., 6=Friday
     /// We convert to standard convention: 0=Sunday, 1=Monday, ..., 6=Saturday
@@ -131,11 +205,128 @@ impl HebrewDate {
         }
     }
     
+    /// Get the weekday, propagating conversion errors
+    pub fn try_day_of_week(&self) -> Result<Weekday, CalendarError> {
+        let rd = DateConverter::hebrew_to_rd(*self)?;
+        Ok(DateConverter::weekday_of_rd(rd))
+    }
+    
+    /// Get the R.D. (the crate's day count, shared with `DateConverter::rd_of`)
+    pub fn to_rd(&self) -> Result<i32, CalendarError> {
+        DateConverter::hebrew_to_rd(*self)
+    }
+    
+    /// The Hebrew date for an R.D.
+    pub fn from_rd(rd: i32) -> Result<Self, CalendarError> {
+        DateConverter::rd_to_hebrew(rd)
+    }
+    
     /// Get the Julian Day Number for this Hebrew date
     pub fn to_julian_day(&self) -> Result<i32, CalendarError> {
         let rd = DateConverter::hebrew_to_rd(*self)?;
         Ok(DateConverter::rd_to_julian_day(rd))
     }
+    
+    /// Get the Gregorian date (shorthand for `DateConverter::hebrew_to_gregorian`)
+    pub fn gregorian(&self) -> Result<NaiveDate, CalendarError> {
+        DateConverter::hebrew_to_gregorian(*self)
+    }
+    
+    /// Plain (year, month number, day) for FFI callers
+    ///
+    /// The month number is leap-aware: Adar I is 12 and Adar II is 13 in leap years.
+    pub fn as_tuple(&self) -> (i32, u8, u8) {
+        let is_leap = DateConverter::is_hebrew_leap_year(self.year);
+        (self.year, self.month.to_number(is_leap), self.day)
+    }
+    
+    /// Inverse of `as_tuple`
+    pub fn from_tuple(year: i32, month_num: u8, day: u8) -> Result<Self, CalendarError> {
+        let month = HebrewMonth::from_number(month_num, DateConverter::is_hebrew_leap_year(year))?;
+        Ok(Self::new(year, month, day))
+    }
+    
+    /// Number of days in this date's month (29 or 30, depending on the year)
+    pub fn days_in_month(&self) -> u8 {
+        let is_leap = DateConverter::is_hebrew_leap_year(self.year);
+        DateConverter::days_in_hebrew_month(self.year, self.month.to_number(is_leap))
+    }
+    
+    /// Advance by `n` Hebrew months (negative to go back)
+    ///
+    /// Months are counted as they occur, so a leap year's Adar I is one of
+    /// them. The day is clamped to the target month's length, e.g. 30 Tishrei
+    /// plus one month is 29 Cheshvan in a year whose Cheshvan is short.
+    pub fn add_months(&self, n: i32) -> HebrewDate {
+        let months_in = DateConverter::months_in_hebrew_year;
+        let number = self.month.to_number(DateConverter::is_hebrew_leap_year(self.year)) as i64;
+        // Position counted from Tishrei (0) through Elul
+        let index = if number >= 7 { number - 7 } else { number + months_in(self.year) as i64 - 7 };
+        
+        // The leap pattern repeats every 19 years (235 months)
+        let mut position = index + n as i64;
+        let cycles = position.div_euclid(235);
+        let mut year = self.year + 19 * cycles as i32;
+        position -= 235 * cycles;
+        while position >= months_in(year) as i64 {
+            position -= months_in(year) as i64;
+            year += 1;
+        }
+        
+        let months = months_in(year);
+        let number = (position as u8 + 7 - 1) % months + 1;
+        let month = HebrewMonth::from_number(number, months == 13).unwrap_or(HebrewMonth::Tishrei);
+        let day = self.day.min(DateConverter::days_in_hebrew_month(year, number));
+        HebrewDate::new(year, month, day)
+    }
+    
+    /// Weekday this month and day falls on in another Hebrew year
+    ///
+    /// Adar (or Adar II) maps to Adar in a common year and Adar II in a leap
+    /// year. Errors if the date does not exist in `year`: Adar I in a common
+    /// year, or the 30th of a month that has 29 days there. Use
+    /// `HebrewCalendar::adar_observance` first to move an Adar I date instead.
+    pub fn weekday_in_year(&self, year: i32) -> Result<Weekday, CalendarError> {
+        DateConverter::weekday_of(year, self.month, self.day)
+    }
+    
+    /// Days left in the month after this one
+    pub fn days_remaining_in_month(&self) -> u8 {
+        self.days_in_month().saturating_sub(self.day)
+    }
+    
+    /// Days of the month before this one
+    ///
+    /// Elapsed + 1 + remaining is always the month's length.
+    pub fn days_elapsed_in_month(&self) -> u8 {
+        self.day.saturating_sub(1)
+    }
+    
+    /// Check whether this Hebrew date falls on the given Gregorian date
+    pub fn is_same_day_as(&self, gregorian: NaiveDate) -> bool {
+        self.gregorian().is_ok_and(|date| date == gregorian)
+    }
+}
+
+/// How Gregorian years at or before 1 BCE are written in display strings
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum YearNumbering {
+    /// 1 BCE, 2 BCE, ... and 1 AD onwards
+    #[default]
+    Historical,
+    /// Year 0 is 1 BCE, year -1 is 2 BCE; no era suffix
+    Astronomical,
+}
+
+impl YearNumbering {
+    /// Display an ISO (astronomical) year number, e.g. 0 as "1 BCE" or "0"
+    pub fn format_year(self, year: i32) -> String {
+        match self {
+            YearNumbering::Historical if year <= 0 => format!("{} BCE", 1 - year),
+            YearNumbering::Historical => format!("{} AD", year),
+            YearNumbering::Astronomical => year.to_string(),
+        }
+    }
 }
 
 /// Represents a Gregorian date for serialization
@@ -150,19 +341,139 @@ pub struct GregorianDate {
 
 impl From<NaiveDate> for GregorianDate {
     fn from(date: NaiveDate) -> Self {
-        let year = date.year();
-        let display = if year <= 0 {
-            format!("{} {}, {} BCE", date.month(), date.day(), 1 - year)
-        } else {
-            format!("{} {}, {} AD", date.month(), date.day(), year)
-        };
-        
         Self {
-            year,
+            year: date.year(),
             month: date.month() as u8,
             day: date.day() as u8,
             iso_string: date.to_string(),
-            display,
+            display: HebrewCalendar::format_display_date(date),
+        }
+    }
+}
+
+impl GregorianDate {
+    /// Rewrite `display` with the given year numbering
+    pub fn with_year_numbering(mut self, numbering: YearNumbering) -> Self {
+        self.display = format!("{} {}, {}", self.month, self.day, numbering.format_year(self.year));
+        self
+    }
+    
+    /// Get the R.D. of this date
+    pub fn to_rd(&self) -> Result<i32, CalendarError> {
+        NaiveDate::from_ymd_opt(self.year, self.month as u32, self.day as u32)
+            .map(DateConverter::rd_of)
+            .ok_or_else(|| CalendarError::InvalidDateFormat(format!(
+                "Invalid Gregorian date {}-{:02}-{:02}", self.year, self.month, self.day
+            )))
+    }
+}
+
+/// The molad (mean lunar conjunction) of a Hebrew month
+///
+/// This is the calendrical molad emtza'i, not the astronomical molad amiti
+/// (true conjunction), which can differ from it by up to about 14 hours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Molad {
+    pub year: i32,
+    pub month: HebrewMonth,
+    /// Civil date and time of the molad (Jerusalem mean time)
+    pub datetime: NaiveDateTime,
+    /// Day of the week in Jewish reckoning (the day begins at 6 PM)
+    pub weekday: Weekday,
+    /// Civil hour (0-23)
+    pub hours: u8,
+    pub minutes: u8,
+    /// Parts of a minute (18 chalakim = 1 minute)
+    pub chalakim: u8,
+}
+
+impl Molad {
+    /// The molad as read aloud on Shabbat Mevarchim
+    ///
+    /// e.g. "The molad will be on Friday, 49 minutes and 0 chalakim after 5 in
+    /// the morning". Evening hours belong to the next Jewish day and are read
+    /// as "the night of" that day.
+    pub fn announce(&self) -> String {
+        let weekday = match self.weekday {
+            Weekday::Sun => "Sunday",
+            Weekday::Mon => "Monday",
+            Weekday::Tue => "Tuesday",
+            Weekday::Wed => "Wednesday",
+            Weekday::Thu => "Thursday",
+            Weekday::Fri => "Friday",
+            Weekday::Sat => "Shabbat",
+        };
+        let (day, period) = match self.hours {
+            0..=11 => (weekday.to_string(), "in the morning"),
+            12..=17 => (weekday.to_string(), "in the afternoon"),
+            _ => (format!("the night of {}", weekday), "in the evening"),
+        };
+        let hour = match self.hours % 12 {
+            0 => 12,
+            h => h,
+        };
+        let minutes = if self.minutes == 1 { "minute" } else { "minutes" };
+        let chalakim = if self.chalakim == 1 { "chelek" } else { "chalakim" };
+        
+        format!(
+            "The molad will be on {}, {} {} and {} {} after {} {}",
+            day, self.minutes, minutes, self.chalakim, chalakim, hour, period
+        )
+    }
+}
+
+/// What a shul announces on Shabbat Mevarchim
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoshChodeshAnnouncement {
+    pub month: HebrewMonth,
+    pub molad: Molad,
+    /// The one or two days of Rosh Chodesh
+    pub days: Vec<Weekday>,
+}
+
+/// How long after the molad a community waits before Kiddush Levana
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KiddushLevanaStart {
+    /// 72 hours after the molad
+    #[default]
+    ThreeDays,
+    /// Seven days after the molad, following the Kabbalists
+    SevenDays,
+}
+
+impl KiddushLevanaStart {
+    fn delay(self) -> Duration {
+        match self {
+            KiddushLevanaStart::ThreeDays => Duration::hours(72),
+            KiddushLevanaStart::SevenDays => Duration::days(7),
+        }
+    }
+}
+
+/// A quarter of the year between Shmuel's tekufot
+///
+/// Shmuel's tekufot assume a 365¼-day year, so they drift from the
+/// astronomical equinoxes and solstices (Tekufat Nisan now falls on April 7 or 8).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Season {
+    /// Spring
+    Nisan,
+    /// Summer
+    Tammuz,
+    /// Autumn
+    Tishrei,
+    /// Winter
+    Tevet,
+}
+
+impl Season {
+    /// Get the English name, e.g. "Tekufat Nisan"
+    pub fn name(&self) -> &'static str {
+        match self {
+            Season::Nisan => "Tekufat Nisan",
+            Season::Tammuz => "Tekufat Tammuz",
+            Season::Tishrei => "Tekufat Tishrei",
+            Season::Tevet => "Tekufat Tevet",
         }
     }
 }
@@ -184,6 +495,38 @@ impl DateConverter {
     /// Actually we use the simplified form: 13753 parts = 12 hours + 793 parts
     const PARTS_PER_LUNATION: i64 = 13753;
     
+    /// Parts in a full lunation (29 days, 12 hours, 793 parts)
+    const PARTS_PER_MONTH: i64 = 765433;
+    
+    /// Molad of Tishrei year 1 (BaHaRaD): 5 hours 204 parts after 6 PM
+    /// on the evening before the epoch
+    const MOLAD_BAHARAD_PARTS: i64 = 5 * 1080 + 204;
+    
+    /// Minutes between Shmuel's tekufot: 91 days 7½ hours
+    const TEKUFA_MINUTES: i64 = 91 * 1440 + 7 * 60 + 30;
+    
+    /// Tekufat Nisan at the start of the 206th great cycle: 6 PM, April 7, 2009
+    /// (Jerusalem mean time), the eve of Birkat HaChama
+    const TEKUFAT_NISAN_5769: (i32, u32, u32) = (2009, 4, 7);
+    
+    /// The tekufa in effect on a civil date
+    ///
+    /// A tekufa falling at any hour of the date counts for the whole day.
+    pub fn tekufa_of(date: NaiveDate) -> Season {
+        let (year, month, day) = Self::TEKUFAT_NISAN_5769;
+        let anchor = NaiveDate::from_ymd_opt(year, month, day).unwrap_or_default();
+        // Minutes from the anchor (18:00) to the end of `date`, counted in whole
+        // days so that NaiveDate::MAX has no following midnight to overflow into
+        let minutes = (date - anchor).num_days() * 24 * 60 + 6 * 60;
+        
+        match (minutes - 1).div_euclid(Self::TEKUFA_MINUTES).rem_euclid(4) {
+            0 => Season::Nisan,
+            1 => Season::Tammuz,
+            2 => Season::Tishrei,
+            _ => Season::Tevet,
+        }
+    }
+    
     /// Convert Gregorian date to Hebrew date
     pub fn gregorian_to_hebrew(date: NaiveDate) -> Result<HebrewDate, CalendarError> {
         let rd = Self::gregorian_to_rd(date);
@@ -208,15 +551,187 @@ impl DateConverter {
         Self::hebrew_new_year(year)
     }
     
+    /// Get the two Hebrew years that share a Gregorian year
+    ///
+    /// Returns (year on January 1, year on December 31), e.g. 2024 → (5784, 5785).
+    /// Errors if either end of the Gregorian year is outside the supported range.
+    pub fn hebrew_years_overlapping(gregorian_year: i32) -> Result<(i32, i32), CalendarError> {
+        let year_on = |month: u32, day: u32| -> Result<i32, CalendarError> {
+            let date = NaiveDate::from_ymd_opt(gregorian_year, month, day).ok_or_else(|| {
+                CalendarError::InvalidDateFormat(format!("Invalid year {}", gregorian_year))
+            })?;
+            Ok(Self::gregorian_to_hebrew(date)?.year)
+        };
+        
+        Ok((year_on(1, 1)?, year_on(12, 31)?))
+    }
+    
+    /// Calculate the molad of a Hebrew month
+    ///
+    /// Errors for Adar I in a common year.
+    pub fn molad(year: i32, month: HebrewMonth) -> Result<Molad, CalendarError> {
+        let month_num = Self::month_number_in(year, month)?;
+        
+        // Months from Tishrei to the target month within the year
+        let offset = if month_num >= 7 {
+            month_num - 7
+        } else {
+            Self::months_in_hebrew_year(year) - 7 + month_num
+        };
+        let months_elapsed = (235i64 * year as i64 - 234) / 19 + offset as i64;
+        
+        // Parts since midnight of R.D. 0
+        let total = (Self::HEBREW_EPOCH_RD as i64 - 1) * Self::PARTS_PER_DAY
+            + 18 * 1080
+            + Self::MOLAD_BAHARAD_PARTS
+            + months_elapsed * Self::PARTS_PER_MONTH;
+        let rd = total.div_euclid(Self::PARTS_PER_DAY) as i32;
+        let parts = total.rem_euclid(Self::PARTS_PER_DAY);
+        
+        let hours = (parts / 1080) as u8;
+        let minutes = (parts % 1080 / 18) as u8;
+        let chalakim = (parts % 18) as u8;
+        
+        // The Jewish day begins at 6 PM
+        let weekday = if hours >= 18 {
+            Self::weekday_of_rd(rd + 1)
+        } else {
+            Self::weekday_of_rd(rd)
+        };
+        
+        let datetime = Self::rd_to_gregorian(rd)?.and_time(NaiveTime::MIN)
+            + Duration::seconds(parts * 10 / 3);
+        
+        Ok(Molad {
+            year,
+            month,
+            datetime,
+            weekday,
+            hours,
+            minutes,
+            chalakim,
+        })
+    }
+    
+    /// Get the weekday(s) of Rosh Chodesh for a Hebrew month
+    ///
+    /// Two days when the preceding month has 30 days (its 30th and the 1st),
+    /// otherwise just the 1st.
+    pub fn rosh_chodesh_days(year: i32, month: HebrewMonth) -> Result<Vec<Weekday>, CalendarError> {
+        Self::month_number_in(year, month)?;
+        let first = Self::hebrew_to_rd(HebrewDate::new(year, month, 1))?;
+        let (prev_year, prev_month) = Self::previous_month(year, month);
+        
+        if Self::days_in_hebrew_month(prev_year, prev_month) == 30 {
+            Ok(vec![Self::weekday_of_rd(first - 1), Self::weekday_of_rd(first)])
+        } else {
+            Ok(vec![Self::weekday_of_rd(first)])
+        }
+    }
+    
+    /// Weekday of a Hebrew date, without going through a Gregorian date
+    ///
+    /// Errors if the date does not exist in `year`: Adar I in a common year,
+    /// or the 30th of a month that has 29 days there.
+    pub fn weekday_of(year: i32, month: HebrewMonth, day: u8) -> Result<Weekday, CalendarError> {
+        let month_num = Self::month_number_in(year, month)?;
+        if day == 0 || day > Self::days_in_hebrew_month(year, month_num) {
+            return Err(CalendarError::InvalidDateFormat(
+                format!("{} {} does not exist in {}", day, month.name(), year)
+            ));
+        }
+        let first = Self::hebrew_to_rd(HebrewDate::new(year, month, 1))?;
+        Ok(Self::weekday_of_rd(first + day as i32 - 1))
+    }
+    
+    /// Month number of `month` in `year`, rejecting Adar I in a common year
+    fn month_number_in(year: i32, month: HebrewMonth) -> Result<u8, CalendarError> {
+        let is_leap = Self::is_hebrew_leap_year(year);
+        if month == HebrewMonth::AdarI && !is_leap {
+            return Err(CalendarError::InvalidDateFormat(
+                format!("Adar I does not exist in {}, a common year", year)
+            ));
+        }
+        Ok(month.to_number(is_leap))
+    }
+    
+    /// Days of a Hebrew month that fall on Shabbat
+    pub fn shabbatot_in_month(year: i32, month: HebrewMonth) -> Result<Vec<u8>, CalendarError> {
+        let length = Self::days_in_hebrew_month(year, Self::month_number_in(year, month)?);
+        let first_shabbat = 7 - Self::weekday_of(year, month, 1)?.num_days_from_sunday() as u8;
+        Ok((first_shabbat..=length).step_by(7).collect())
+    }
+    
+    /// Dates between which Kiddush Levana may be said for a month
+    ///
+    /// Runs from 72 hours (or seven days, per `start`) after the molad until
+    /// half a lunation (14 days, 18 hours, 22 minutes) after it.
+    pub fn kiddush_levana_window(
+        year: i32,
+        month: HebrewMonth,
+        start: KiddushLevanaStart,
+    ) -> Result<(NaiveDate, NaiveDate), CalendarError> {
+        let molad = Self::molad(year, month)?.datetime;
+        let half_month_seconds = Self::PARTS_PER_MONTH / 2 * 10 / 3;
+        
+        Ok((
+            (molad + start.delay()).date(),
+            (molad + Duration::seconds(half_month_seconds)).date(),
+        ))
+    }
+    
+    /// Build the full Rosh Chodesh announcement (molad and days)
+    pub fn rosh_chodesh_announcement(year: i32, month: HebrewMonth) -> Result<RoshChodeshAnnouncement, CalendarError> {
+        Ok(RoshChodeshAnnouncement {
+            month,
+            molad: Self::molad(year, month)?,
+            days: Self::rosh_chodesh_days(year, month)?,
+        })
+    }
+    
     /// Convert Gregorian date to R.D. (days since Jan 1, year 1)
     pub fn gregorian_to_rd(date: NaiveDate) -> i32 {
         let jd = Self::gregorian_to_julian_day(date);
         Self::julian_day_to_rd(jd)
     }
     
+    /// R.D. of a Gregorian date, for arithmetic alongside `HebrewDate::to_rd`
+    pub fn rd_of(date: NaiveDate) -> i32 {
+        Self::gregorian_to_rd(date)
+    }
+    
     /// Convert R.D. to Gregorian date
     pub fn rd_to_gregorian(rd: i32) -> Result<NaiveDate, CalendarError> {
-        let jd = Self::rd_to_julian_day(rd);
+        let jd = rd.checked_add(1721424).ok_or_else(|| CalendarError::CalculationError(
+            format!("R.D. {} overflows Julian Day", rd)
+        ))?;
+        Self::julian_day_to_gregorian(jd)
+    }
+    
+    /// Convert a Julian calendar date to the (proleptic) Gregorian date
+    ///
+    /// For sources dated before the 1582 reform, e.g. Julian 1492-10-12 is
+    /// Gregorian 1492-10-21.
+    pub fn julian_to_gregorian(year: i32, month: u32, day: u32) -> Result<NaiveDate, CalendarError> {
+        let month_length = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if year.rem_euclid(4) == 0 => 29,
+            2 => 28,
+            _ => 0,
+        };
+        if day == 0 || day > month_length {
+            return Err(CalendarError::InvalidDateFormat(
+                format!("{}-{:02}-{:02} is not a valid Julian date", year, month, day)
+            ));
+        }
+        
+        let a = (14 - month as i64) / 12;
+        let y = year as i64 + 4800 - a;
+        let m = month as i64 + 12 * a - 3;
+        let jd = day as i64 + (153 * m + 2) / 5 + 365 * y + y.div_euclid(4) - 32083;
+        
+        let jd = i32::try_from(jd).map_err(|_| CalendarError::date_out_of_range(format!("{:04}-{:02}-{:02}", year, month, day)))?;
         Self::julian_day_to_gregorian(jd)
     }
     
@@ -231,6 +746,9 @@ impl DateConverter {
     }
     
     /// Convert Gregorian date to Julian Day Number
+    ///
+    /// Computed in i64; chrono limits years to ±262,143, so the result
+    /// (under 10^8 in magnitude) always fits in i32.
     fn gregorian_to_julian_day(date: NaiveDate) -> i32 {
         let year = date.year() as i64;
         let month = date.month() as i64;
@@ -266,7 +784,17 @@ impl DateConverter {
     /// Check if a Hebrew year is a leap year
     /// A year is leap if (7*y + 1) mod 19 < 7
     pub fn is_hebrew_leap_year(year: i32) -> bool {
-        (7 * year + 1).rem_euclid(19) < 7
+        (7 * year as i64 + 1).rem_euclid(19) < 7
+    }
+    
+    /// First Hebrew leap year strictly after `after`, or `None` past `i32::MAX`
+    pub fn next_leap_year(after: i32) -> Option<i32> {
+        (after.checked_add(1)?..=after.saturating_add(19)).find(|&y| Self::is_hebrew_leap_year(y))
+    }
+    
+    /// Last Hebrew leap year strictly before `before`, or `None` below `i32::MIN`
+    pub fn previous_leap_year(before: i32) -> Option<i32> {
+        (before.saturating_sub(19)..before).rev().find(|&y| Self::is_hebrew_leap_year(y))
     }
     
     /// Get the number of months in a Hebrew year (12 or 13)
@@ -276,8 +804,8 @@ impl DateConverter {
     
     /// Get the number of days in a Hebrew year
     pub fn days_in_hebrew_year(year: i32) -> u16 {
-        let rosh_next = Self::hebrew_new_year(year + 1);
-        let rosh_this = Self::hebrew_new_year(year);
+        let rosh_next = Self::hebrew_new_year_days(year as i64 + 1);
+        let rosh_this = Self::hebrew_new_year_days(year as i64);
         (rosh_next - rosh_this) as u16
     }
     
@@ -296,17 +824,76 @@ impl DateConverter {
         }
     }
     
+    /// Length of Cheshvan: 30 in complete years, otherwise 29
+    pub fn cheshvan_length(year: i32) -> u8 {
+        Self::days_in_hebrew_month(year, 8)
+    }
+    
+    /// Length of Kislev: 29 in deficient years, otherwise 30
+    pub fn kislev_length(year: i32) -> u8 {
+        Self::days_in_hebrew_month(year, 9)
+    }
+    
+    /// Summary of a Hebrew year: length, type, keviyah, months and Shmita status
+    pub fn year_info(year: i32) -> Result<HebrewYearInfo, CalendarError> {
+        let is_leap = Self::is_hebrew_leap_year(year);
+        let year_type = Self::hebrew_year_type(year);
+        let rosh_hashanah = Self::rd_to_gregorian(Self::hebrew_new_year(year))?;
+        
+        // Keviyah: weekday of Rosh Hashanah, Cheshvan/Kislev pattern, weekday of Pesach
+        let weekday_letter = |weekday: Weekday| {
+            ['א', 'ב', 'ג', 'ד', 'ה', 'ו', 'ז'][weekday.num_days_from_sunday() as usize]
+        };
+        let pattern = match year_type {
+            YearType::DeficientCommon | YearType::DeficientLeap => 'ח',
+            YearType::RegularCommon | YearType::RegularLeap => 'כ',
+            YearType::CompleteCommon | YearType::CompleteLeap => 'ש',
+        };
+        let keviyah = [
+            weekday_letter(Self::weekday_of(year, HebrewMonth::Tishrei, 1)?),
+            pattern,
+            weekday_letter(Self::weekday_of(year, HebrewMonth::Nisan, 15)?),
+        ]
+        .iter()
+        .collect();
+        
+        let months = (0..Self::months_in_hebrew_year(year))
+            .map(|i| {
+                let number = (i + 6) % Self::months_in_hebrew_year(year) + 1;
+                let month = HebrewMonth::from_number(number, is_leap)?;
+                Ok(match month {
+                    HebrewMonth::Adar if is_leap => "Adar II",
+                    month => month.name(),
+                }
+                .to_string())
+            })
+            .collect::<Result<Vec<_>, CalendarError>>()?;
+        
+        Ok(HebrewYearInfo {
+            year,
+            is_leap,
+            year_type,
+            length: Self::days_in_hebrew_year(year),
+            keviyah,
+            rosh_hashanah: rosh_hashanah.to_string(),
+            months,
+            is_shmita: year.rem_euclid(7) == 0,
+        })
+    }
+    
     /// Calculate the number of days elapsed from the epoch to the molad of Tishrei
     /// for the given Hebrew year, with initial postponement adjustment.
     /// Based on the algorithm from "Calendrical Calculations" 4th ed.
-    fn hebrew_calendar_elapsed_days(year: i32) -> i64 {
+    fn hebrew_calendar_elapsed_days(year: i64) -> i64 {
         // Months elapsed from year 1 to year (year-1)
         // = floor((235 * year - 234) / 19)
-        let months_elapsed = ((235i64 * year as i64 - 234) / 19) as i64;
+        let months_elapsed = (235 * year - 234) / 19;
         
-        // Parts elapsed: the molad of Tishrei year 1 was at 5 hours 204 parts
-        // which is 5604 parts after the epoch. The constant 12084 includes
-        // this offset plus adjustments for the epoch calculation.
+        // Parts elapsed: 12084 = 11 hours 204 parts is the molad of Tishrei
+        // year 1 (BaHaRaD: Monday 5h 204p in Jewish reckoning, i.e. Sunday
+        // 23:11 and 6 parts civil time) measured from noon on the Sunday
+        // before the epoch. Counting from noon folds in molad zaken: a molad
+        // at or after noon rolls over into the next day.
         let parts_elapsed: i64 = 12084 + Self::PARTS_PER_LUNATION * months_elapsed;
         
         // Days elapsed: 29 days per month plus parts converted to days
@@ -325,7 +912,7 @@ impl DateConverter {
     
     /// Calculate the year length correction to prevent invalid year lengths
     /// Returns additional days to delay Rosh Hashanah (0, 1, or 2)
-    fn hebrew_year_length_correction(year: i32) -> i64 {
+    fn hebrew_year_length_correction(year: i64) -> i64 {
         let ny0 = Self::hebrew_calendar_elapsed_days(year - 1);
         let ny1 = Self::hebrew_calendar_elapsed_days(year);
         let ny2 = Self::hebrew_calendar_elapsed_days(year + 1);
@@ -342,11 +929,27 @@ impl DateConverter {
     }
     
     /// Calculate R.D. of Rosh Hashanah for a given Hebrew year
+    ///
+    /// Years whose new year does not fit in i32 saturate; use
+    /// `checked_new_year` where the result must be exact.
     fn hebrew_new_year(year: i32) -> i32 {
+        Self::hebrew_new_year_days(year as i64)
+            .clamp(i32::MIN as i64, i32::MAX as i64) as i32
+    }
+    
+    /// Calculate R.D. of Rosh Hashanah, failing if it overflows i32
+    fn checked_new_year(year: i32) -> Result<i32, CalendarError> {
+        i32::try_from(Self::hebrew_new_year_days(year as i64)).map_err(|_| {
+            CalendarError::CalculationError(format!("Hebrew year {} is out of range", year))
+        })
+    }
+    
+    /// Calculate R.D. of Rosh Hashanah in i64
+    fn hebrew_new_year_days(year: i64) -> i64 {
         let elapsed = Self::hebrew_calendar_elapsed_days(year);
         let correction = Self::hebrew_year_length_correction(year);
         
-        (Self::HEBREW_EPOCH_RD as i64 + elapsed + correction) as i32
+        Self::HEBREW_EPOCH_RD as i64 + elapsed + correction
     }
     
     /// Convert Hebrew date to R.D.
@@ -354,8 +957,14 @@ impl DateConverter {
         let is_leap = Self::is_hebrew_leap_year(hebrew.year);
         let month_num = hebrew.month.to_number(is_leap);
         
+        if hebrew.day == 0 {
+            return Err(CalendarError::CalculationError(
+                format!("Invalid Hebrew day 0 in {:?}", hebrew)
+            ));
+        }
+        
         // Start at Rosh Hashanah of the target year
-        let mut rd = Self::hebrew_new_year(hebrew.year) as i64;
+        let mut rd = Self::checked_new_year(hebrew.year)? as i64;
         
         // Add days for each month from Tishrei (month 7) to target month
         if month_num >= 7 {
@@ -377,29 +986,48 @@ impl DateConverter {
         }
         
         // Add days (day 1 is the first day, so subtract 1)
-        rd += (hebrew.day - 1) as i64;
+        rd += hebrew.day as i64 - 1;
         
-        Ok(rd as i32)
+        i32::try_from(rd).map_err(|_| CalendarError::CalculationError(
+            format!("R.D. of {:?} overflows", hebrew)
+        ))
+    }
+    
+    /// Integer estimate of the Hebrew year containing an R.D., within a year
+    ///
+    /// Uses the mean year of the 19-year cycle (235 months, about 6939.69 days).
+    fn estimate_hebrew_year(rd: i64) -> i32 {
+        let days = rd - Self::HEBREW_EPOCH_RD as i64;
+        let years = days * Self::PARTS_PER_DAY * 19 / (235 * Self::PARTS_PER_MONTH);
+        (years + 1).clamp(i32::MIN as i64, i32::MAX as i64) as i32
     }
     
     /// Convert R.D. to Hebrew date
     fn rd_to_hebrew(rd: i32) -> Result<HebrewDate, CalendarError> {
         let rd_i64 = rd as i64;
         
-        // Approximate year
-        let mut year = ((rd_i64 - Self::HEBREW_EPOCH_RD as i64) as f64 / 365.25) as i32 + 1;
-        year = year.max(1);
+        // Hebrew year 1 begins at the epoch; nothing earlier is representable
+        if rd < Self::HEBREW_EPOCH_RD {
+            let epoch = Self::rd_to_gregorian(Self::HEBREW_EPOCH_RD)?;
+            return Err(CalendarError::DateOutOfRange {
+                date: format!("R.D. {}", rd),
+                min: epoch.to_string(),
+                max: HebrewCalendar::MAX_DATE.to_string(),
+            });
+        }
+        
+        let mut year = Self::estimate_hebrew_year(rd_i64);
         
         // Adjust to correct year
-        while rd < Self::hebrew_new_year(year) {
+        while rd < Self::checked_new_year(year)? {
             year -= 1;
         }
-        while rd >= Self::hebrew_new_year(year + 1) {
+        while rd >= Self::checked_new_year(year + 1)? {
             year += 1;
         }
         
         let is_leap = Self::is_hebrew_leap_year(year);
-        let start_of_year = Self::hebrew_new_year(year) as i64;
+        let start_of_year = Self::checked_new_year(year)? as i64;
         let mut days_into_year = rd_i64 - start_of_year;
         
         // Find the month
@@ -411,15 +1039,14 @@ impl DateConverter {
             days_in_first_part += Self::days_in_hebrew_month(year, m) as i64;
         }
         
-        let month: u8;
-        if days_into_year < days_in_first_part {
+        let month: u8 = if days_into_year < days_in_first_part {
             // We're in the first part (Tishrei through Adar/Adar II)
             let mut m = 7u8;
             while days_into_year >= Self::days_in_hebrew_month(year, m) as i64 {
                 days_into_year -= Self::days_in_hebrew_month(year, m) as i64;
                 m += 1;
             }
-            month = m;
+            m
         } else {
             // We're in the second part (Nisan through Elul)
             days_into_year -= days_in_first_part;
@@ -428,8 +1055,8 @@ impl DateConverter {
                 days_into_year -= Self::days_in_hebrew_month(year, m) as i64;
                 m += 1;
             }
-            month = m;
-        }
+            m
+        };
         
         let day = (days_into_year + 1) as u8;
         
@@ -440,8 +1067,102 @@ impl DateConverter {
         ))
     }
     
+    /// Get the weekday of an R.D. date (R.D. 0 is a Saturday)
+    fn weekday_of_rd(rd: i32) -> Weekday {
+        Weekday::try_from((rd.rem_euclid(7) as u8 + 5) % 7).unwrap_or(Weekday::Sat)
+    }
+    
+    /// Get the (year, month number) of the month preceding a Hebrew month
+    pub(crate) fn previous_month(year: i32, month: HebrewMonth) -> (i32, u8) {
+        match month.to_number(Self::is_hebrew_leap_year(year)) {
+            7 => (year - 1, 6),
+            1 => (year, Self::months_in_hebrew_year(year)),
+            n => (year, n - 1),
+        }
+    }
+    
+    /// Parse a Hebrew date written in gematria, e.g. "ט״ו בניסן תשפ״ד"
+    ///
+    /// Accepts the output of `HebrewDate::format_hebrew`, with or without the ב prefix
+    /// on the month and an optional thousands prefix on the year (ה׳תשפ״ד).
+    /// A year without thousands is taken to be in the sixth millennium; a round
+    /// millennium is written with אלפים ("ה׳ אלפים").
+    pub fn parse_hebrew_gematria(input: &str) -> Result<HebrewDate, CalendarError> {
+        let invalid = || CalendarError::InvalidDateFormat(input.to_string());
+        
+        let tokens: Vec<&str> = input.split_whitespace().collect();
+        if tokens.len() < 3 {
+            return Err(invalid());
+        }
+        let day = gematria::parse_numeral(tokens[0]).ok_or_else(invalid)?;
+        let (year, year_tokens) = match tokens[..] {
+            [.., thousands, "אלפים"] if tokens.len() >= 4 => {
+                (gematria::parse_numeral(thousands).ok_or_else(invalid)? as i32 * 1000, 2)
+            }
+            [.., year] => (Self::parse_gematria_year(year).ok_or_else(invalid)?, 1),
+            [] => return Err(invalid()),
+        };
+        
+        let month_text = tokens[1..tokens.len() - year_tokens].join(" ");
+        let month_text = month_text.strip_prefix('ב').unwrap_or(&month_text);
+        let is_leap = Self::is_hebrew_leap_year(year);
+        let month = match month_text.replace(['\'', '׳'], "").as_str() {
+            "תשרי" => HebrewMonth::Tishrei,
+            "חשון" | "חשוון" | "מרחשון" | "מרחשוון" => HebrewMonth::Cheshvan,
+            "כסלו" | "כסליו" => HebrewMonth::Kislev,
+            "טבת" => HebrewMonth::Teves,
+            "שבט" => HebrewMonth::Shevat,
+            "אדר" => HebrewMonth::Adar,
+            "אדר א" if is_leap => HebrewMonth::AdarI,
+            "אדר ב" if is_leap => HebrewMonth::Adar,
+            "ניסן" => HebrewMonth::Nisan,
+            "אייר" | "איר" => HebrewMonth::Iyar,
+            "סיון" | "סיוון" => HebrewMonth::Sivan,
+            "תמוז" => HebrewMonth::Tammuz,
+            "אב" => HebrewMonth::Av,
+            "אלול" => HebrewMonth::Elul,
+            _ => return Err(invalid()),
+        };
+        
+        let max_day = Self::days_in_hebrew_month(year, month.to_number(is_leap));
+        if day == 0 || day > max_day as u32 {
+            return Err(invalid());
+        }
+        Ok(HebrewDate::new(year, month, day as u8))
+    }
+    
+    /// Parse a gematria year, with an optional geresh-marked thousands letter
+    fn parse_gematria_year(text: &str) -> Option<i32> {
+        let mut chars = text.chars();
+        let first = chars.next()?;
+        let rest = chars.as_str();
+        match rest.strip_prefix(['׳', '\'']) {
+            Some(remainder) if !remainder.is_empty() => {
+                let thousands = gematria::parse_numeral(&first.to_string())?;
+                Some((thousands * 1000 + gematria::parse_numeral(remainder)?) as i32)
+            }
+            _ => Some(5000 + gematria::parse_numeral(text)? as i32),
+        }
+    }
+    
+    /// The Hebrew date following `date`, without a round trip through RD
+    pub(crate) fn next_hebrew_day(date: &HebrewDate) -> Result<HebrewDate, CalendarError> {
+        let is_leap = Self::is_hebrew_leap_year(date.year);
+        let month = date.month.to_number(is_leap);
+        
+        if date.day < Self::days_in_hebrew_month(date.year, month) {
+            return Ok(HebrewDate::new(date.year, date.month, date.day + 1));
+        }
+        match month {
+            // Elul ends the year
+            6 => Ok(HebrewDate::new(date.year + 1, HebrewMonth::Tishrei, 1)),
+            m if m == Self::months_in_hebrew_year(date.year) => Ok(HebrewDate::new(date.year, HebrewMonth::Nisan, 1)),
+            m => Ok(HebrewDate::new(date.year, HebrewMonth::from_number(m + 1, is_leap)?, 1)),
+        }
+    }
+    
     /// Get the number of days in a Hebrew month
-    fn days_in_hebrew_month(year: i32, month: u8) -> u8 {
+    pub(crate) fn days_in_hebrew_month(year: i32, month: u8) -> u8 {
         let year_type = Self::hebrew_year_type(year);
         let is_leap = Self::is_hebrew_leap_year(year);
         
@@ -470,8 +1191,37 @@ impl DateConverter {
     }
 }
 
+/// Check that a Gregorian date survives a Gregorian → Hebrew → Gregorian round trip
+///
+/// Exposed for external fuzzers; returns false on any conversion error.
+#[cfg(any(test, feature = "testutil"))]
+pub fn roundtrip_check(date: NaiveDate) -> bool {
+    DateConverter::gregorian_to_hebrew(date)
+        .and_then(DateConverter::hebrew_to_gregorian)
+        .map(|back| back == date)
+        .unwrap_or(false)
+}
+
+/// Summary of a Hebrew year (see `DateConverter::year_info`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HebrewYearInfo {
+    pub year: i32,
+    pub is_leap: bool,
+    pub year_type: YearType,
+    /// Length in days (353–355 or 383–385)
+    pub length: u16,
+    /// Three-letter keviyah, e.g. "השג"
+    pub keviyah: String,
+    /// Gregorian date of Rosh Hashanah (ISO format)
+    pub rosh_hashanah: String,
+    /// Month names from Tishrei through Elul
+    pub months: Vec<String>,
+    pub is_shmita: bool,
+}
+
 /// Hebrew year type classification
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum YearType {
     DeficientCommon,  // 353 days
     RegularCommon,    // 354 days
@@ -622,6 +1372,51 @@ mod tests {
         assert_eq!(days_5783, 355, "Year 5783 should have 355 days");
     }
     
+    #[test]
+    fn test_next_and_previous_leap_year() {
+        assert_eq!(DateConverter::next_leap_year(5784), Some(5787));
+        assert_eq!(DateConverter::previous_leap_year(5784), Some(5782));
+        // A leap year itself is excluded from both searches
+        assert_eq!(DateConverter::next_leap_year(5787), Some(5790));
+        assert_eq!(DateConverter::previous_leap_year(5787), Some(5784));
+        // Across a cycle boundary: year 19 (5795) to year 3 (5798)
+        assert_eq!(DateConverter::next_leap_year(5795), Some(5798));
+        
+        // No leap year remains at the ends of i32
+        assert_eq!(DateConverter::next_leap_year(i32::MAX - 1), None);
+        assert_eq!(DateConverter::next_leap_year(i32::MAX), None);
+        assert_eq!(DateConverter::next_leap_year(i32::MAX - 3), Some(i32::MAX - 2));
+        assert_eq!(DateConverter::previous_leap_year(i32::MIN + 1), None);
+        assert_eq!(DateConverter::previous_leap_year(i32::MIN), None);
+        assert_eq!(DateConverter::previous_leap_year(i32::MIN + 2), Some(i32::MIN + 1));
+    }
+    
+    #[test]
+    fn test_next_hebrew_day_matches_conversion() {
+        // Three years spanning leap and common years and both year boundaries
+        let start = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+        let mut hebrew = DateConverter::gregorian_to_hebrew(start).unwrap();
+        for date in start.iter_days().skip(1).take(3 * 366) {
+            hebrew = DateConverter::next_hebrew_day(&hebrew).unwrap();
+            assert_eq!(hebrew, DateConverter::gregorian_to_hebrew(date).unwrap(), "{}", date);
+        }
+    }
+    
+    #[test]
+    fn test_rd_roundtrip() {
+        let rosh_hashanah = HebrewDate::new(5784, HebrewMonth::Tishrei, 1);
+        let gregorian = NaiveDate::from_ymd_opt(2023, 9, 16).unwrap();
+        let rd = rosh_hashanah.to_rd().unwrap();
+        
+        assert_eq!(rd, DateConverter::rd_of(gregorian));
+        assert_eq!(GregorianDate::from(gregorian).to_rd().unwrap(), rd);
+        assert_eq!(HebrewDate::from_rd(rd).unwrap(), rosh_hashanah);
+        // Arithmetic in RD space: Yom Kippur is nine days later
+        assert_eq!(HebrewDate::from_rd(rd + 9).unwrap(), HebrewDate::new(5784, HebrewMonth::Tishrei, 10));
+        
+        assert!(HebrewDate::new(5784, HebrewMonth::Tishrei, 0).to_rd().is_err());
+    }
+    
     #[test]
     fn test_leap_year_months() {
         // 5784 is a leap year, should have 13 months
@@ -672,4 +1467,562 @@ mod tests {
         // So Saturday should be 5
         assert_eq!(greg.weekday().num_days_from_monday(), 5, "Should be Saturday");
     }
+
+    #[test]
+    fn test_molad_tishrei_5784() {
+        // Friday, September 15, 2023, 5:49 AM and 0 chalakim
+        let molad = DateConverter::molad(5784, HebrewMonth::Tishrei).unwrap();
+        assert_eq!(molad.datetime.date(), NaiveDate::from_ymd_opt(2023, 9, 15).unwrap());
+        assert_eq!(molad.weekday, Weekday::Fri);
+        assert_eq!((molad.hours, molad.minutes, molad.chalakim), (5, 49, 0));
+    }
+
+    #[test]
+    fn test_molad_evening_belongs_to_next_day() {
+        // Molad Cheshvan 5784: Saturday evening 6:33 PM, i.e. Sunday in Jewish reckoning
+        let molad = DateConverter::molad(5784, HebrewMonth::Cheshvan).unwrap();
+        assert_eq!(molad.datetime.date(), NaiveDate::from_ymd_opt(2023, 10, 14).unwrap());
+        assert_eq!((molad.hours, molad.minutes, molad.chalakim), (18, 33, 1));
+        assert_eq!(molad.weekday, Weekday::Sun);
+    }
+
+    #[test]
+    fn test_molad_announce() {
+        assert_eq!(
+            DateConverter::molad(5784, HebrewMonth::Tishrei).unwrap().announce(),
+            "The molad will be on Friday, 49 minutes and 0 chalakim after 5 in the morning"
+        );
+        assert_eq!(
+            DateConverter::molad(5784, HebrewMonth::Cheshvan).unwrap().announce(),
+            "The molad will be on the night of Sunday, 33 minutes and 1 chelek after 6 in the evening"
+        );
+    }
+
+    #[test]
+    fn test_julian_to_gregorian() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(DateConverter::julian_to_gregorian(1582, 10, 4).unwrap(), ymd(1582, 10, 14));
+        assert_eq!(DateConverter::julian_to_gregorian(1492, 10, 12).unwrap(), ymd(1492, 10, 21));
+        // 1500 is a Julian but not a Gregorian leap year
+        assert_eq!(DateConverter::julian_to_gregorian(1500, 2, 29).unwrap(), ymd(1500, 3, 10));
+        assert!(DateConverter::julian_to_gregorian(1501, 2, 29).is_err());
+    }
+
+    #[test]
+    fn test_try_day_of_week() {
+        // Elul 29, 5783 (Friday) → Tishrei 1, 5784 (Shabbat)
+        assert_eq!(HebrewDate::new(5783, HebrewMonth::Elul, 29).try_day_of_week().unwrap(), Weekday::Fri);
+        assert_eq!(HebrewDate::new(5784, HebrewMonth::Tishrei, 1).try_day_of_week().unwrap(), Weekday::Sat);
+        assert!(HebrewDate::new(5784, HebrewMonth::Tishrei, 0).try_day_of_week().is_err());
+    }
+
+    #[test]
+    fn test_day_of_week_advances_by_one() {
+        // Consecutive days across month and year boundaries, 5780 through 5790
+        let start = NaiveDate::from_ymd_opt(2019, 9, 1).unwrap();
+        let mut previous = DateConverter::gregorian_to_hebrew(start).unwrap().try_day_of_week().unwrap();
+        for offset in 1..(11 * 385) {
+            let date = start + Duration::days(offset);
+            let hebrew = DateConverter::gregorian_to_hebrew(date).unwrap();
+            let weekday = hebrew.try_day_of_week().unwrap();
+            assert_eq!(weekday, previous.succ(), "at {:?}", hebrew);
+            assert_eq!(weekday, date.weekday());
+            assert_eq!(hebrew.day_of_week() as u32, weekday.num_days_from_sunday());
+            previous = weekday;
+        }
+    }
+
+    #[test]
+    fn test_rosh_chodesh_two_days() {
+        // Tishrei 5784 has 30 days: Rosh Chodesh Cheshvan is Sun Oct 15 and Mon Oct 16, 2023
+        let days = DateConverter::rosh_chodesh_days(5784, HebrewMonth::Cheshvan).unwrap();
+        assert_eq!(days, vec![Weekday::Sun, Weekday::Mon]);
+    }
+
+    #[test]
+    fn test_rosh_chodesh_one_day() {
+        // Iyar has 29 days: Rosh Chodesh Sivan 5784 is only Friday, June 7, 2024
+        let days = DateConverter::rosh_chodesh_days(5784, HebrewMonth::Sivan).unwrap();
+        assert_eq!(days, vec![Weekday::Fri]);
+    }
+
+    #[test]
+    fn test_rosh_chodesh_announcement() {
+        let announcement = DateConverter::rosh_chodesh_announcement(5784, HebrewMonth::Cheshvan).unwrap();
+        assert_eq!(announcement.month, HebrewMonth::Cheshvan);
+        assert_eq!(announcement.molad, DateConverter::molad(5784, HebrewMonth::Cheshvan).unwrap());
+        assert_eq!(announcement.days.len(), 2);
+    }
+
+    #[test]
+    fn test_earliest_supported_dates() {
+        // Jan 1, 1 CE = 18 Teves 3761; Rosh Hashanah 3761 fell in 1 BCE (year 0)
+        let jan_1_ce = NaiveDate::from_ymd_opt(1, 1, 1).unwrap();
+        let hebrew = DateConverter::gregorian_to_hebrew(jan_1_ce).unwrap();
+        assert_eq!(hebrew.year, 3761);
+        assert_eq!(hebrew.month, HebrewMonth::Teves);
+        assert_eq!(hebrew.day, 18);
+        assert_eq!(DateConverter::hebrew_to_gregorian(hebrew).unwrap(), jan_1_ce);
+
+        let jan_1_bce = NaiveDate::from_ymd_opt(0, 1, 1).unwrap();
+        let hebrew = DateConverter::gregorian_to_hebrew(jan_1_bce).unwrap();
+        assert_eq!(hebrew.year, 3760);
+        assert_eq!(DateConverter::hebrew_to_gregorian(hebrew).unwrap(), jan_1_bce);
+
+        let rh_3761 = DateConverter::rd_to_gregorian(DateConverter::rosh_hashanah(3761)).unwrap();
+        assert_eq!(rh_3761.year(), 0);
+    }
+
+    #[test]
+    fn test_hebrew_epoch_boundary() {
+        let epoch = DateConverter::rd_to_gregorian(DateConverter::rosh_hashanah(1)).unwrap();
+        let hebrew = DateConverter::gregorian_to_hebrew(epoch).unwrap();
+        assert_eq!(hebrew, HebrewDate::new(1, HebrewMonth::Tishrei, 1));
+
+        let before = epoch.pred_opt().unwrap();
+        match DateConverter::gregorian_to_hebrew(before) {
+            Err(CalendarError::DateOutOfRange { min, .. }) => assert_eq!(min, epoch.to_string()),
+            other => panic!("Expected DateOutOfRange, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_hebrew_years_overlapping() {
+        assert_eq!(DateConverter::hebrew_years_overlapping(2024).unwrap(), (5784, 5785));
+        assert_eq!(DateConverter::hebrew_years_overlapping(2023).unwrap(), (5783, 5784));
+        // Rosh Hashanah 5766 fell very late, on October 4, 2005
+        assert_eq!(DateConverter::hebrew_years_overlapping(2005).unwrap(), (5765, 5766));
+        assert!(matches!(
+            DateConverter::hebrew_years_overlapping(-4000),
+            Err(CalendarError::DateOutOfRange { .. })
+        ));
+        assert!(DateConverter::hebrew_years_overlapping(i32::MAX).is_err());
+    }
+
+    #[test]
+    fn test_extreme_supported_dates() {
+        for (y, m, d) in [(0, 1, 1), (2050, 12, 31)] {
+            let date = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+            let hebrew = DateConverter::gregorian_to_hebrew(date).unwrap();
+            assert_eq!(DateConverter::hebrew_to_gregorian(hebrew).unwrap(), date);
+        }
+        let last = DateConverter::gregorian_to_hebrew(NaiveDate::from_ymd_opt(2050, 12, 31).unwrap()).unwrap();
+        assert_eq!(last.year, 5811);
+        
+        // Before the epoch: the error carries the supported maximum, not chrono's
+        let before_epoch = NaiveDate::from_ymd_opt(-3761, 1, 1).unwrap();
+        match DateConverter::gregorian_to_hebrew(before_epoch) {
+            Err(CalendarError::DateOutOfRange { max, .. }) => {
+                assert_eq!(max, HebrewCalendar::MAX_DATE.to_string());
+            }
+            other => panic!("expected DateOutOfRange, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_overflow_is_an_error() {
+        let huge = HebrewDate::new(i32::MAX, HebrewMonth::Tishrei, 1);
+        assert!(matches!(
+            DateConverter::hebrew_to_gregorian(huge),
+            Err(CalendarError::CalculationError(_))
+        ));
+        assert!(matches!(
+            DateConverter::rd_to_gregorian(i32::MAX),
+            Err(CalendarError::CalculationError(_))
+        ));
+    }
+
+    #[test]
+    fn test_day_zero_is_an_error() {
+        let invalid = HebrewDate::new(5784, HebrewMonth::Tishrei, 0);
+        assert!(matches!(
+            DateConverter::hebrew_to_gregorian(invalid),
+            Err(CalendarError::CalculationError(_))
+        ));
+    }
+
+    #[test]
+    fn test_hebrew_date_as_hash_key() {
+        use std::collections::HashMap;
+        let mut cache = HashMap::new();
+        cache.insert(HebrewDate::new(5784, HebrewMonth::Tishrei, 1), "Rosh Hashanah");
+        assert_eq!(cache.get(&HebrewDate::new(5784, HebrewMonth::Tishrei, 1)), Some(&"Rosh Hashanah"));
+        assert_eq!(cache.get(&HebrewDate::new(5784, HebrewMonth::Tishrei, 2)), None);
+    }
+    
+    #[test]
+    fn test_roundtrip_sweep_supported_range() {
+        // Every day from 1 BCE through 2050 CE must round-trip, and each
+        // successive day must advance the Hebrew date by exactly one RD
+        let mut date = NaiveDate::from_ymd_opt(0, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2050, 12, 31).unwrap();
+        let mut prev_rd = None;
+        
+        while date <= end {
+            assert!(roundtrip_check(date), "round trip failed for {}", date);
+            
+            let hebrew = DateConverter::gregorian_to_hebrew(date).unwrap();
+            let rd = DateConverter::hebrew_to_rd(hebrew).unwrap();
+            if let Some(prev) = prev_rd {
+                assert_eq!(rd, prev + 1, "RD not monotonic at {}", date);
+            }
+            prev_rd = Some(rd);
+            
+            date = date.succ_opt().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_month_name_variants() {
+        use TransliterationStyle::*;
+        
+        assert_eq!(HebrewMonth::Teves.name(), "Teves");
+        assert_eq!(HebrewMonth::Teves.name_variant(Ashkenazi), "Teves");
+        assert_eq!(HebrewMonth::Teves.name_variant(Sephardi), "Tevet");
+        assert_eq!(HebrewMonth::Shevat.name_variant(Ashkenazi), "Shevat");
+        assert_eq!(HebrewMonth::Shevat.name_variant(Sephardi), "Shvat");
+        assert_eq!(HebrewMonth::Cheshvan.name_variant(Academic), "Marheshvan");
+        assert_eq!(HebrewMonth::Nisan.name_variant(Academic), "Nisan");
+    }
+    
+    #[test]
+    fn test_month_hebrew_names() {
+        assert_eq!(HebrewMonth::Tishrei.hebrew_name(), "תשרי");
+        assert_eq!(HebrewMonth::Teves.hebrew_name(), "טבת");
+        assert_eq!(HebrewMonth::Shevat.hebrew_name(), "שבט");
+        assert_eq!(HebrewMonth::AdarI.hebrew_name(), "אדר א׳");
+        assert_eq!(HebrewMonth::Elul.hebrew_name(), "אלול");
+    }
+
+    #[test]
+    fn test_molad_tishrei_year_1_is_baharad() {
+        // BaHaRaD: 2nd day (Monday), 5 hours 204 parts after 6 PM Sunday
+        let molad = DateConverter::molad(1, HebrewMonth::Tishrei).unwrap();
+        assert_eq!(molad.weekday, Weekday::Mon);
+        assert_eq!((molad.hours, molad.minutes, molad.chalakim), (23, 11, 6));
+        assert_eq!(
+            DateConverter::gregorian_to_rd(molad.datetime.date()),
+            DateConverter::HEBREW_EPOCH_RD - 1
+        );
+        
+        let parts_after_six_pm = (molad.hours as i64 - 18) * 1080
+            + molad.minutes as i64 * 18
+            + molad.chalakim as i64;
+        assert_eq!(parts_after_six_pm, DateConverter::MOLAD_BAHARAD_PARTS);
+    }
+    
+    #[test]
+    fn test_rosh_hashanah_5700_to_5800_reference() {
+        // Tishrei 1 for 5700..=5800, derived independently from the molad and
+        // the four dechiyot rather than the elapsed-days formula
+        const REFERENCE: [&str; 101] = [
+            "1939-09-14", "1940-10-03", "1941-09-22", "1942-09-12", "1943-09-30",
+            "1944-09-18", "1945-09-08", "1946-09-26", "1947-09-15", "1948-10-04",
+            "1949-09-24", "1950-09-12", "1951-10-01", "1952-09-20", "1953-09-10",
+            "1954-09-28", "1955-09-17", "1956-09-06", "1957-09-26", "1958-09-15",
+            "1959-10-03", "1960-09-22", "1961-09-11", "1962-09-29", "1963-09-19",
+            "1964-09-07", "1965-09-27", "1966-09-15", "1967-10-05", "1968-09-23",
+            "1969-09-13", "1970-10-01", "1971-09-20", "1972-09-09", "1973-09-27",
+            "1974-09-17", "1975-09-06", "1976-09-25", "1977-09-13", "1978-10-02",
+            "1979-09-22", "1980-09-11", "1981-09-29", "1982-09-18", "1983-09-08",
+            "1984-09-27", "1985-09-16", "1986-10-04", "1987-09-24", "1988-09-12",
+            "1989-09-30", "1990-09-20", "1991-09-09", "1992-09-28", "1993-09-16",
+            "1994-09-06", "1995-09-25", "1996-09-14", "1997-10-02", "1998-09-21",
+            "1999-09-11", "2000-09-30", "2001-09-18", "2002-09-07", "2003-09-27",
+            "2004-09-16", "2005-10-04", "2006-09-23", "2007-09-13", "2008-09-30",
+            "2009-09-19", "2010-09-09", "2011-09-29", "2012-09-17", "2013-09-05",
+            "2014-09-25", "2015-09-14", "2016-10-03", "2017-09-21", "2018-09-10",
+            "2019-09-30", "2020-09-19", "2021-09-07", "2022-09-26", "2023-09-16",
+            "2024-10-03", "2025-09-23", "2026-09-12", "2027-10-02", "2028-09-21",
+            "2029-09-10", "2030-09-28", "2031-09-18", "2032-09-06", "2033-09-24",
+            "2034-09-14", "2035-10-04", "2036-09-22", "2037-09-10", "2038-09-30",
+            "2039-09-19",
+        ];
+        
+        for (i, expected) in REFERENCE.iter().enumerate() {
+            let year = 5700 + i as i32;
+            let rh = DateConverter::hebrew_to_gregorian(HebrewDate::new(year, HebrewMonth::Tishrei, 1))
+                .unwrap();
+            assert_eq!(rh.to_string(), *expected, "Rosh Hashanah {}", year);
+        }
+    }
+
+    #[test]
+    fn test_is_same_day_as() {
+        let rh = HebrewDate::new(5784, HebrewMonth::Tishrei, 1);
+        assert_eq!(rh.gregorian().unwrap(), NaiveDate::from_ymd_opt(2023, 9, 16).unwrap());
+        assert!(rh.is_same_day_as(NaiveDate::from_ymd_opt(2023, 9, 16).unwrap()));
+        assert!(!rh.is_same_day_as(NaiveDate::from_ymd_opt(2023, 9, 17).unwrap()));
+        
+        // An invalid Hebrew date matches nothing
+        assert!(!HebrewDate::new(5784, HebrewMonth::Tishrei, 0).is_same_day_as(NaiveDate::MIN));
+    }
+
+    #[test]
+    fn test_add_months() {
+        let date = HebrewDate::new;
+        
+        // Short Cheshvan in 5784 clamps the day
+        assert_eq!(date(5784, HebrewMonth::Tishrei, 30).add_months(1), date(5784, HebrewMonth::Cheshvan, 29));
+        assert_eq!(date(5785, HebrewMonth::Tishrei, 30).add_months(1), date(5785, HebrewMonth::Cheshvan, 30));
+        
+        // Adar I is inserted in leap years
+        assert_eq!(date(5784, HebrewMonth::Shevat, 15).add_months(1), date(5784, HebrewMonth::AdarI, 15));
+        assert_eq!(date(5784, HebrewMonth::Shevat, 15).add_months(2), date(5784, HebrewMonth::Adar, 15));
+        assert_eq!(date(5785, HebrewMonth::Shevat, 15).add_months(1), date(5785, HebrewMonth::Adar, 15));
+        assert_eq!(date(5784, HebrewMonth::AdarI, 30).add_months(1), date(5784, HebrewMonth::Adar, 29));
+        assert_eq!(date(5783, HebrewMonth::Nisan, 1).add_months(12), date(5784, HebrewMonth::Adar, 1));
+        assert_eq!(date(5783, HebrewMonth::Nisan, 1).add_months(13), date(5784, HebrewMonth::Nisan, 1));
+        
+        // Backwards, across the year boundary and whole cycles
+        assert_eq!(date(5784, HebrewMonth::Nisan, 1).add_months(-2), date(5784, HebrewMonth::AdarI, 1));
+        assert_eq!(date(5785, HebrewMonth::Tishrei, 10).add_months(-1), date(5784, HebrewMonth::Elul, 10));
+        assert_eq!(date(5784, HebrewMonth::Kislev, 25).add_months(235), date(5803, HebrewMonth::Kislev, 25));
+        assert_eq!(date(5784, HebrewMonth::Kislev, 25).add_months(0), date(5784, HebrewMonth::Kislev, 25));
+        
+        // Month by month agrees with the month of the next Rosh Chodesh
+        let mut current = date(5780, HebrewMonth::Tishrei, 1);
+        for _ in 0..100 {
+            let next = current.add_months(1);
+            let last = HebrewDate::new(current.year, current.month, current.days_in_month());
+            assert_eq!(DateConverter::next_hebrew_day(&last).unwrap(), next);
+            current = next;
+        }
+    }
+    
+    #[test]
+    fn test_weekday_in_year() {
+        let rosh_hashanah = HebrewDate::new(5784, HebrewMonth::Tishrei, 1);
+        assert_eq!(rosh_hashanah.weekday_in_year(5784).unwrap(), Weekday::Sat);
+        assert_eq!(rosh_hashanah.weekday_in_year(5785).unwrap(), Weekday::Thu);
+        assert_eq!(rosh_hashanah.weekday_in_year(5786).unwrap(), Weekday::Tue);
+        
+        // Adar lands in Adar II of a leap year: 15 Adar II 5784 was Monday, March 25, 2024
+        let shushan_purim = HebrewDate::new(5785, HebrewMonth::Adar, 15);
+        assert_eq!(shushan_purim.weekday_in_year(5785).unwrap(), Weekday::Sat);
+        assert_eq!(shushan_purim.weekday_in_year(5784).unwrap(), Weekday::Mon);
+        
+        // Adar I exists only in leap years
+        let adar_i = HebrewDate::new(5784, HebrewMonth::AdarI, 10);
+        assert!(adar_i.weekday_in_year(5785).is_err());
+        assert_eq!(
+            adar_i.weekday_in_year(5787).unwrap(),
+            HebrewDate::new(5787, HebrewMonth::AdarI, 10).gregorian().unwrap().weekday()
+        );
+        
+        // 30 Cheshvan is missing when Cheshvan is short (5784)
+        let long_cheshvan = HebrewDate::new(5785, HebrewMonth::Cheshvan, 30);
+        assert!(long_cheshvan.weekday_in_year(5784).is_err());
+        assert!(long_cheshvan.weekday_in_year(5785).is_ok());
+        
+        // Agrees with the Gregorian weekday across leap and common years
+        let date = HebrewDate::new(5770, HebrewMonth::Nisan, 14);
+        for year in 5770..5800 {
+            let expected = HebrewDate::new(year, HebrewMonth::Nisan, 14).gregorian().unwrap().weekday();
+            assert_eq!(date.weekday_in_year(year).unwrap(), expected);
+        }
+    }
+    
+    #[test]
+    fn test_days_remaining_in_month() {
+        // Kislev has 29 days in 5784 (deficient) and 30 in 5785 (complete)
+        let short = HebrewDate::new(5784, HebrewMonth::Kislev, 20);
+        let long = HebrewDate::new(5785, HebrewMonth::Kislev, 20);
+        assert_eq!(short.days_remaining_in_month(), 9);
+        assert_eq!(long.days_remaining_in_month(), 10);
+        assert_eq!(short.days_elapsed_in_month(), 19);
+        
+        assert_eq!(HebrewDate::new(5785, HebrewMonth::Kislev, 30).days_remaining_in_month(), 0);
+        assert_eq!(HebrewDate::new(5784, HebrewMonth::AdarI, 1).days_in_month(), 30);
+        assert_eq!(HebrewDate::new(5784, HebrewMonth::Adar, 1).days_in_month(), 29);
+        assert_eq!(HebrewDate::new(5784, HebrewMonth::Tishrei, 1).days_elapsed_in_month(), 0);
+    }
+    
+    #[test]
+    fn test_tuple_roundtrip_adar_leap_year() {
+        // 5784 is a leap year: Adar I = 12, Adar II = 13
+        let adar_i = HebrewDate::new(5784, HebrewMonth::AdarI, 14);
+        let adar_ii = HebrewDate::new(5784, HebrewMonth::Adar, 14);
+        assert_eq!(adar_i.as_tuple(), (5784, 12, 14));
+        assert_eq!(adar_ii.as_tuple(), (5784, 13, 14));
+        assert_eq!(HebrewDate::from_tuple(5784, 12, 14).unwrap(), adar_i);
+        assert_eq!(HebrewDate::from_tuple(5784, 13, 14).unwrap(), adar_ii);
+        
+        // In a common year Adar is 12 and 13 is rejected
+        assert_eq!(HebrewDate::new(5785, HebrewMonth::Adar, 1).as_tuple(), (5785, 12, 1));
+        assert!(HebrewDate::from_tuple(5785, 13, 1).is_err());
+    }
+    
+    #[test]
+    fn test_shabbatot_in_month() {
+        // Rosh Hashanah 5784 fell on Saturday, September 16, 2023
+        assert_eq!(DateConverter::weekday_of(5784, HebrewMonth::Tishrei, 1).unwrap(), Weekday::Sat);
+        assert_eq!(DateConverter::weekday_of(5784, HebrewMonth::Tishrei, 10).unwrap(), Weekday::Mon);
+        assert_eq!(
+            DateConverter::shabbatot_in_month(5784, HebrewMonth::Tishrei).unwrap(),
+            vec![1, 8, 15, 22, 29]
+        );
+        
+        for day in DateConverter::shabbatot_in_month(5785, HebrewMonth::Adar).unwrap() {
+            let date = HebrewDate::new(5785, HebrewMonth::Adar, day).gregorian().unwrap();
+            assert_eq!(date.weekday(), Weekday::Sat);
+        }
+    }
+    
+    #[test]
+    fn test_month_helpers_reject_missing_dates() {
+        // 5785 is a common year with no Adar I; 5784 is deficient, so Kislev has 29 days
+        assert!(DateConverter::molad(5785, HebrewMonth::AdarI).is_err());
+        assert!(DateConverter::rosh_chodesh_days(5785, HebrewMonth::AdarI).is_err());
+        assert!(DateConverter::shabbatot_in_month(5785, HebrewMonth::AdarI).is_err());
+        assert!(DateConverter::weekday_of(5785, HebrewMonth::AdarI, 1).is_err());
+        assert!(DateConverter::weekday_of(5784, HebrewMonth::Kislev, 30).is_err());
+        assert!(DateConverter::weekday_of(5785, HebrewMonth::Tishrei, 0).is_err());
+        assert!(DateConverter::molad(5784, HebrewMonth::AdarI).is_ok());
+    }
+    
+    #[test]
+    fn test_year_type_serde() {
+        let cases = [
+            (YearType::DeficientCommon, "\"deficient_common\""),
+            (YearType::RegularCommon, "\"regular_common\""),
+            (YearType::CompleteCommon, "\"complete_common\""),
+            (YearType::DeficientLeap, "\"deficient_leap\""),
+            (YearType::RegularLeap, "\"regular_leap\""),
+            (YearType::CompleteLeap, "\"complete_leap\""),
+        ];
+        for (year_type, json) in cases {
+            assert_eq!(serde_json::to_string(&year_type).unwrap(), json);
+            assert_eq!(serde_json::from_str::<YearType>(json).unwrap(), year_type);
+        }
+    }
+    
+    #[test]
+    fn test_tekufa_of() {
+        let on = |y, m, d| DateConverter::tekufa_of(NaiveDate::from_ymd_opt(y, m, d).unwrap());
+        // Tekufat Tammuz 5784: July 7, 2024 at 7:30 PM
+        assert_eq!(on(2024, 7, 6), Season::Nisan);
+        assert_eq!(on(2024, 7, 7), Season::Tammuz);
+        assert_eq!(on(2024, 7, 15), Season::Tammuz);
+        
+        assert_eq!(on(2009, 4, 7), Season::Nisan);
+        assert_eq!(on(2009, 4, 6), Season::Tevet);
+        assert_eq!(on(2023, 12, 1), Season::Tishrei);
+        assert_eq!(on(2024, 2, 1), Season::Tevet);
+        assert_eq!(on(1950, 5, 1), Season::Nisan);
+        
+        // The extremes of NaiveDate do not overflow
+        DateConverter::tekufa_of(NaiveDate::MAX);
+        DateConverter::tekufa_of(NaiveDate::MIN);
+    }
+    
+    #[test]
+    fn test_estimate_hebrew_year_within_one() {
+        let first = DateConverter::HEBREW_EPOCH_RD;
+        let last = DateConverter::gregorian_to_rd(NaiveDate::MAX);
+        for rd in (first..last).step_by(997).chain([first, last]) {
+            let year = DateConverter::rd_to_hebrew(rd).unwrap().year;
+            let estimate = DateConverter::estimate_hebrew_year(rd as i64);
+            assert!((estimate - year).abs() <= 1, "R.D. {}: estimated {} for {}", rd, estimate, year);
+        }
+    }
+    
+    #[test]
+    fn test_cheshvan_kislev_lengths() {
+        let lengths = |year| (DateConverter::cheshvan_length(year), DateConverter::kislev_length(year));
+        assert_eq!(lengths(5784), (29, 29)); // deficient leap
+        assert_eq!(lengths(5786), (29, 30)); // regular common
+        assert_eq!(lengths(5785), (30, 30)); // complete common
+        
+        for year in 5700..5800 {
+            let expected = match DateConverter::hebrew_year_type(year) {
+                YearType::DeficientCommon | YearType::DeficientLeap => (29, 29),
+                YearType::RegularCommon | YearType::RegularLeap => (29, 30),
+                YearType::CompleteCommon | YearType::CompleteLeap => (30, 30),
+            };
+            assert_eq!(lengths(year), expected, "year {}", year);
+        }
+    }
+    
+    #[test]
+    fn test_year_info() {
+        let info = DateConverter::year_info(5784).unwrap();
+        assert!(info.is_leap);
+        assert_eq!(info.length, 383);
+        assert_eq!(info.year_type, YearType::DeficientLeap);
+        assert_eq!(info.keviyah, "זחג");
+        assert_eq!(info.rosh_hashanah, "2023-09-16");
+        assert_eq!(info.months.len(), 13);
+        assert_eq!(info.months[0], "Tishrei");
+        assert_eq!(info.months[5], "Adar I");
+        assert_eq!(info.months[6], "Adar II");
+        assert_eq!(info.months[12], "Elul");
+        assert!(!info.is_shmita);
+        
+        assert!(DateConverter::year_info(5782).unwrap().is_shmita);
+        assert_eq!(DateConverter::year_info(5785).unwrap().months[5], "Adar");
+    }
+    
+    #[test]
+    fn test_format_hebrew() {
+        assert_eq!(HebrewDate::new(5784, HebrewMonth::Nisan, 15).format_hebrew(), "ט״ו בניסן תשפ״ד");
+        assert_eq!(HebrewDate::new(5784, HebrewMonth::AdarI, 1).format_hebrew(), "א׳ באדר א׳ תשפ״ד");
+        assert_eq!(HebrewDate::new(5784, HebrewMonth::Adar, 14).format_hebrew(), "י״ד באדר ב׳ תשפ״ד");
+        assert_eq!(HebrewDate::new(5785, HebrewMonth::Adar, 16).format_hebrew(), "ט״ז באדר תשפ״ה");
+    }
+    
+    #[test]
+    fn test_parse_hebrew_gematria() {
+        let parsed = DateConverter::parse_hebrew_gematria("ט״ו בניסן תשפ״ד").unwrap();
+        assert_eq!(parsed, HebrewDate::new(5784, HebrewMonth::Nisan, 15));
+        
+        // Thousands prefix, no ב prefix and ASCII punctuation are all accepted
+        let parsed = DateConverter::parse_hebrew_gematria("כ\"ה כסלו ה'תשפ\"ד").unwrap();
+        assert_eq!(parsed, HebrewDate::new(5784, HebrewMonth::Kislev, 25));
+        
+        assert!(DateConverter::parse_hebrew_gematria("ל׳ בטבת תשפ״ד").is_err());
+        assert!(DateConverter::parse_hebrew_gematria("א׳ באדר א׳ תשפ״ה").is_err());
+        assert!(DateConverter::parse_hebrew_gematria("ט״ו ניסן").is_err());
+    }
+    
+    #[test]
+    fn test_parse_hebrew_gematria_roundtrip() {
+        let dates = [
+            HebrewDate::new(5784, HebrewMonth::Tishrei, 1),
+            HebrewDate::new(5784, HebrewMonth::Cheshvan, 29),
+            HebrewDate::new(5784, HebrewMonth::AdarI, 30),
+            HebrewDate::new(5784, HebrewMonth::Adar, 15),
+            HebrewDate::new(5785, HebrewMonth::Adar, 29),
+            HebrewDate::new(5785, HebrewMonth::Av, 9),
+            HebrewDate::new(5800, HebrewMonth::Sivan, 6),
+            HebrewDate::new(5000, HebrewMonth::Tishrei, 1),
+        ];
+        for date in dates {
+            assert_eq!(DateConverter::parse_hebrew_gematria(&date.format_hebrew()).unwrap(), date);
+        }
+        assert_eq!(HebrewDate::new(5000, HebrewMonth::Tishrei, 1).format_hebrew(), "א׳ בתשרי ה׳ אלפים");
+    }
+
+    #[test]
+    fn test_kiddush_levana_window() {
+        // Molad Cheshvan 5784: Saturday evening, October 14, 2023
+        let (start, end) = DateConverter::kiddush_levana_window(
+            5784, HebrewMonth::Cheshvan, KiddushLevanaStart::ThreeDays
+        ).unwrap();
+        assert_eq!(start, NaiveDate::from_ymd_opt(2023, 10, 17).unwrap());
+        assert_eq!(end, NaiveDate::from_ymd_opt(2023, 10, 29).unwrap());
+        
+        // Waiting seven days moves only the start
+        let (start, end) = DateConverter::kiddush_levana_window(
+            5784, HebrewMonth::Cheshvan, KiddushLevanaStart::SevenDays
+        ).unwrap();
+        assert_eq!(start, NaiveDate::from_ymd_opt(2023, 10, 21).unwrap());
+        assert_eq!(end, NaiveDate::from_ymd_opt(2023, 10, 29).unwrap());
+        
+        for month in [HebrewMonth::Tishrei, HebrewMonth::Nisan, HebrewMonth::Elul] {
+            let (start, end) = DateConverter::kiddush_levana_window(5785, month, KiddushLevanaStart::default()).unwrap();
+            let span = (end - start).num_days();
+            assert!((11..=12).contains(&span), "{:?} span was {} days", month, span);
+        }
+    }
 }
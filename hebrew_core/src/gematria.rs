@@ -0,0 +1,129 @@
+//! Gematria Module
+//!
+//! Hebrew numerals and letter values, used for formatting and parsing dates
+//! and available for labels such as "5784 = ה׳תשפ״ד".
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Numeric value of a single Hebrew letter (final forms count as their base letter)
+fn letter_value(c: char) -> Option<u32> {
+    let value = match c {
+        'א' => 1, 'ב' => 2, 'ג' => 3, 'ד' => 4, 'ה' => 5,
+        'ו' => 6, 'ז' => 7, 'ח' => 8, 'ט' => 9,
+        'י' => 10, 'כ' | 'ך' => 20, 'ל' => 30, 'מ' | 'ם' => 40, 'נ' | 'ן' => 50,
+        'ס' => 60, 'ע' => 70, 'פ' | 'ף' => 80, 'צ' | 'ץ' => 90,
+        'ק' => 100, 'ר' => 200, 'ש' => 300, 'ת' => 400,
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// Gematria of a Hebrew word or phrase: the sum of its letters
+///
+/// Anything that is not a Hebrew letter (spaces, geresh, punctuation) is ignored.
+pub fn value(hebrew_letters: &str) -> u32 {
+    hebrew_letters.chars().filter_map(letter_value).sum()
+}
+
+/// Sum the letters of a Hebrew numeral, ignoring geresh and gershayim
+///
+/// Returns None if any other non-letter character is present.
+pub(crate) fn parse_numeral(hebrew_letters: &str) -> Option<u32> {
+    let mut total = 0;
+    let mut any = false;
+    for c in hebrew_letters.chars() {
+        if matches!(c, '׳' | '״' | '\'' | '"') {
+            continue;
+        }
+        total += letter_value(c)?;
+        any = true;
+    }
+    any.then_some(total)
+}
+
+/// Write a number as a Hebrew numeral with geresh or gershayim
+///
+/// 15 and 16 are written ט״ו and ט״ז to avoid spelling the divine name.
+/// Thousands are written as a separate letter with a geresh: 5784 is ה׳תשפ״ד.
+pub fn to_hebrew_number(n: u32) -> String {
+    if n >= 1000 {
+        let thousands = to_hebrew_number(n / 1000);
+        return match n % 1000 {
+            0 => thousands,
+            rest => thousands + &to_hebrew_number(rest),
+        };
+    }
+    
+    const UNITS: [&str; 10] = ["", "א", "ב", "ג", "ד", "ה", "ו", "ז", "ח", "ט"];
+    const TENS: [&str; 10] = ["", "י", "כ", "ל", "מ", "נ", "ס", "ע", "פ", "צ"];
+
+    let mut letters = String::new();
+    let mut rest = n;
+    while rest >= 400 {
+        letters.push('ת');
+        rest -= 400;
+    }
+    letters.push_str(match rest / 100 {
+        3 => "ש",
+        2 => "ר",
+        1 => "ק",
+        _ => "",
+    });
+    rest %= 100;
+
+    match rest {
+        15 => letters.push_str("טו"),
+        16 => letters.push_str("טז"),
+        _ => {
+            letters.push_str(TENS[(rest / 10) as usize]);
+            letters.push_str(UNITS[(rest % 10) as usize]);
+        }
+    }
+
+    // A single letter takes a geresh; otherwise gershayim precede the last letter
+    let mut chars: Vec<char> = letters.chars().collect();
+    match chars.len() {
+        0 => {}
+        1 => chars.push('׳'),
+        len => chars.insert(len - 1, '״'),
+    }
+    chars.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_hebrew_number() {
+        assert_eq!(to_hebrew_number(1), "א׳");
+        assert_eq!(to_hebrew_number(15), "ט״ו");
+        assert_eq!(to_hebrew_number(16), "ט״ז");
+        assert_eq!(to_hebrew_number(30), "ל׳");
+        assert_eq!(to_hebrew_number(784), "תשפ״ד");
+        assert_eq!(to_hebrew_number(715), "תשט״ו");
+        assert_eq!(to_hebrew_number(248), "רמ״ח");
+        assert_eq!(to_hebrew_number(611), "תרי״א");
+        assert_eq!(to_hebrew_number(5784), "ה׳תשפ״ד");
+        assert_eq!(to_hebrew_number(5000), "ה׳");
+    }
+
+    #[test]
+    fn test_parse_numeral() {
+        assert_eq!(parse_numeral("ט״ו"), Some(15));
+        assert_eq!(parse_numeral("תשפ״ד"), Some(784));
+        assert_eq!(parse_numeral("ך"), Some(20));
+        assert_eq!(parse_numeral("abc"), None);
+        assert_eq!(parse_numeral("״"), None);
+    }
+
+    #[test]
+    fn test_value() {
+        assert_eq!(value("תורה"), 611);
+        assert_eq!(value("רמ״ח"), 248);
+        assert_eq!(value("חי"), 18);
+        assert_eq!(value("שלום עליכם"), 376 + 170);
+        assert_eq!(value("abc"), 0);
+    }
+}
@@ -2,10 +2,15 @@
 //! 
 //! Implements identification of Jewish holidays based on Hebrew calendar dates.
 
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Weekday};
 use serde::{Deserialize, Serialize};
 
 use crate::calendar::{DateConverter, HebrewDate, HebrewMonth};
-use crate::CalendarError;
+use crate::zmanim::GeoLocation;
+use crate::{CalendarError, DayBoundary, HebrewCalendar, Language};
 
 /// Jewish holiday
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -54,8 +59,10 @@ pub enum Holiday {
     PesachCholHamoedDay2,
     PesachCholHamoedDay3,
     PesachCholHamoedDay4,
+    PesachCholHamoedDay5,
     PesachDay7,
     PesachDay8,
+    PesachSheni,
     
     // Counting the Omer
     OmerDay1, OmerDay2, OmerDay3, OmerDay4, OmerDay5, OmerDay6, OmerDay7,
@@ -89,100 +96,15 @@ pub enum Holiday {
 impl Holiday {
     /// Get the English name of the holiday
     pub fn name(&self) -> &'static str {
-        match self {
-            Holiday::RoshHashanahDay1 => "Rosh Hashanah (Day 1)",
-            Holiday::RoshHashanahDay2 => "Rosh Hashanah (Day 2)",
-            Holiday::YomKippur => "Yom Kippur",
-            Holiday::SukkotDay1 => "Sukkot (Day 1)",
-            Holiday::SukkotDay2 => "Sukkot (Day 2)",
-            Holiday::SukkotCholHamoedDay1 => "Sukkot (Chol HaMoed Day 1)",
-            Holiday::SukkotCholHamoedDay2 => "Sukkot (Chol HaMoed Day 2)",
-            Holiday::SukkotCholHamoedDay3 => "Sukkot (Chol HaMoed Day 3)",
-            Holiday::SukkotCholHamoedDay4 => "Sukkot (Chol HaMoed Day 4)",
-            Holiday::SukkotCholHamoedDay5 => "Sukkot (Chol HaMoed Day 5)",
-            Holiday::HoshanaRabbah => "Hoshana Rabbah",
-            Holiday::SheminiAtzeret => "Shemini Atzeret",
-            Holiday::SimchatTorah => "Simchat Torah",
-            Holiday::ChanukahDay1 => "Chanukah (Day 1 - 1 Candle)",
-            Holiday::ChanukahDay2 => "Chanukah (Day 2 - 2 Candles)",
-            Holiday::ChanukahDay3 => "Chanukah (Day 3 - 3 Candles)",
-            Holiday::ChanukahDay4 => "Chanukah (Day 4 - 4 Candles)",
-            Holiday::ChanukahDay5 => "Chanukah (Day 5 - 5 Candles)",
-            Holiday::ChanukahDay6 => "Chanukah (Day 6 - 6 Candles)",
-            Holiday::ChanukahDay7 => "Chanukah (Day 7 - 7 Candles)",
-            Holiday::ChanukahDay8 => "Chanukah (Day 8 - 8 Candles)",
-            Holiday::TuBiShevat => "Tu B'Shevat",
-            Holiday::TaanitEsther => "Ta'anit Esther",
-            Holiday::Purim => "Purim",
-            Holiday::ShushanPurim => "Shushan Purim",
-            Holiday::PesachDay1 => "Pesach (Day 1)",
-            Holiday::PesachDay2 => "Pesach (Day 2)",
-            Holiday::PesachCholHamoedDay1 => "Pesach (Chol HaMoed Day 1)",
-            Holiday::PesachCholHamoedDay2 => "Pesach (Chol HaMoed Day 2)",
-            Holiday::PesachCholHamoedDay3 => "Pesach (Chol HaMoed Day 3)",
-            Holiday::PesachCholHamoedDay4 => "Pesach (Chol HaMoed Day 4)",
-            Holiday::PesachDay7 => "Pesach (Day 7)",
-            Holiday::PesachDay8 => "Pesach (Day 8)",
-            Holiday::LagBaOmer => "Lag BaOmer",
-            Holiday::YomHaShoah => "Yom HaShoah",
-            Holiday::YomHaZikaron => "Yom HaZikaron",
-            Holiday::YomHaAtzmaut => "Yom HaAtzmaut",
-            Holiday::YomYerushalayim => "Yom Yerushalayim",
-            Holiday::ShavuotDay1 => "Shavuot (Day 1)",
-            Holiday::ShavuotDay2 => "Shavuot (Day 2)",
-            Holiday::ShivaAsarBTammuz => "Shiva Asar B'Tammuz",
-            Holiday::TishaBAv => "Tisha B'Av",
-            Holiday::TuBAv => "Tu B'Av",
-            Holiday::RoshChodesh => "Rosh Chodesh",
-            Holiday::OmerDay1 => "Omer Day 1",
-            Holiday::OmerDay2 => "Omer Day 2",
-            Holiday::OmerDay3 => "Omer Day 3",
-            Holiday::OmerDay4 => "Omer Day 4",
-            Holiday::OmerDay5 => "Omer Day 5",
-            Holiday::OmerDay6 => "Omer Day 6",
-            Holiday::OmerDay7 => "Omer Day 7",
-            Holiday::OmerDay8 => "Omer Day 8",
-            Holiday::OmerDay9 => "Omer Day 9",
-            Holiday::OmerDay10 => "Omer Day 10",
-            Holiday::OmerDay11 => "Omer Day 11",
-            Holiday::OmerDay12 => "Omer Day 12",
-            Holiday::OmerDay13 => "Omer Day 13",
-            Holiday::OmerDay14 => "Omer Day 14",
-            Holiday::OmerDay15 => "Omer Day 15",
-            Holiday::OmerDay16 => "Omer Day 16",
-            Holiday::OmerDay17 => "Omer Day 17",
-            Holiday::OmerDay18 => "Omer Day 18",
-            Holiday::OmerDay19 => "Omer Day 19",
-            Holiday::OmerDay20 => "Omer Day 20",
-            Holiday::OmerDay21 => "Omer Day 21",
-            Holiday::OmerDay22 => "Omer Day 22",
-            Holiday::OmerDay23 => "Omer Day 23",
-            Holiday::OmerDay24 => "Omer Day 24",
-            Holiday::OmerDay25 => "Omer Day 25",
-            Holiday::OmerDay26 => "Omer Day 26",
-            Holiday::OmerDay27 => "Omer Day 27",
-            Holiday::OmerDay28 => "Omer Day 28",
-            Holiday::OmerDay29 => "Omer Day 29",
-            Holiday::OmerDay30 => "Omer Day 30",
-            Holiday::OmerDay31 => "Omer Day 31",
-            Holiday::OmerDay32 => "Omer Day 32",
-            Holiday::OmerDay33 => "Omer Day 33 (Lag BaOmer)",
-            Holiday::OmerDay34 => "Omer Day 34",
-            Holiday::OmerDay35 => "Omer Day 35",
-            Holiday::OmerDay36 => "Omer Day 36",
-            Holiday::OmerDay37 => "Omer Day 37",
-            Holiday::OmerDay38 => "Omer Day 38",
-            Holiday::OmerDay39 => "Omer Day 39",
-            Holiday::OmerDay40 => "Omer Day 40",
-            Holiday::OmerDay41 => "Omer Day 41",
-            Holiday::OmerDay42 => "Omer Day 42",
-            Holiday::OmerDay43 => "Omer Day 43",
-            Holiday::OmerDay44 => "Omer Day 44",
-            Holiday::OmerDay45 => "Omer Day 45",
-            Holiday::OmerDay46 => "Omer Day 46",
-            Holiday::OmerDay47 => "Omer Day 47",
-            Holiday::OmerDay48 => "Omer Day 48",
-            Holiday::OmerDay49 => "Omer Day 49",
+        self.name_in(Language::English)
+    }
+    
+    /// Get the name of the holiday in a display language
+    pub fn name_in(&self, lang: Language) -> &'static str {
+        let (_, english, hebrew) = HOLIDAY_NAMES[*self as usize];
+        match lang {
+            Language::English => english,
+            Language::Hebrew => hebrew,
         }
     }
     
@@ -203,6 +125,95 @@ impl Holiday {
         )
     }
     
+    /// Number of Chanukah lights for this day, not counting the shamash
+    ///
+    /// Returns None for non-Chanukah holidays.
+    pub fn chanukah_candle_count(&self) -> Option<u8> {
+        match self {
+            Holiday::ChanukahDay1 => Some(1),
+            Holiday::ChanukahDay2 => Some(2),
+            Holiday::ChanukahDay3 => Some(3),
+            Holiday::ChanukahDay4 => Some(4),
+            Holiday::ChanukahDay5 => Some(5),
+            Holiday::ChanukahDay6 => Some(6),
+            Holiday::ChanukahDay7 => Some(7),
+            Holiday::ChanukahDay8 => Some(8),
+            _ => None,
+        }
+    }
+    
+    /// The fixed Hebrew date of this holiday, if it has one
+    ///
+    /// Dates follow the Diaspora scheme (Chol Hamoed day 5 exists only in
+    /// Israel and returns its Israeli date). Returns None for holidays that
+    /// span or move: Chanukah and Omer days, postponable fasts, Rosh Chodesh
+    /// and the modern Israeli days.
+    pub fn fixed_hebrew_date(&self) -> Option<(HebrewMonth, u8)> {
+        use HebrewMonth::*;
+        let date = match self {
+            Holiday::RoshHashanahDay1 => (Tishrei, 1),
+            Holiday::RoshHashanahDay2 => (Tishrei, 2),
+            Holiday::YomKippur => (Tishrei, 10),
+            Holiday::SukkotDay1 => (Tishrei, 15),
+            Holiday::SukkotDay2 => (Tishrei, 16),
+            Holiday::SukkotCholHamoedDay1 => (Tishrei, 17),
+            Holiday::SukkotCholHamoedDay2 => (Tishrei, 18),
+            Holiday::SukkotCholHamoedDay3 => (Tishrei, 19),
+            Holiday::SukkotCholHamoedDay4 => (Tishrei, 20),
+            Holiday::SukkotCholHamoedDay5 => (Tishrei, 20),
+            Holiday::HoshanaRabbah => (Tishrei, 21),
+            Holiday::SheminiAtzeret => (Tishrei, 22),
+            Holiday::SimchatTorah => (Tishrei, 23),
+            Holiday::TuBiShevat => (Shevat, 15),
+            Holiday::Purim => (Adar, 14),
+            Holiday::ShushanPurim => (Adar, 15),
+            Holiday::PesachDay1 => (Nisan, 15),
+            Holiday::PesachDay2 => (Nisan, 16),
+            Holiday::PesachCholHamoedDay1 => (Nisan, 17),
+            Holiday::PesachCholHamoedDay2 => (Nisan, 18),
+            Holiday::PesachCholHamoedDay3 => (Nisan, 19),
+            Holiday::PesachCholHamoedDay4 => (Nisan, 20),
+            Holiday::PesachCholHamoedDay5 => (Nisan, 20),
+            Holiday::PesachDay7 => (Nisan, 21),
+            Holiday::PesachDay8 => (Nisan, 22),
+            Holiday::PesachSheni => (Iyar, 14),
+            Holiday::LagBaOmer => (Iyar, 18),
+            Holiday::ShavuotDay1 => (Sivan, 6),
+            Holiday::ShavuotDay2 => (Sivan, 7),
+            Holiday::TuBAv => (Av, 15),
+            _ => return None,
+        };
+        Some(date)
+    }
+    
+    /// Display ordering when several observances share a day (lower comes first)
+    ///
+    /// Yom Tov, then Chol Hamoed, Purim and fasts, then Chanukah, Rosh Chodesh,
+    /// minor and modern days, and finally the Omer count.
+    pub fn display_priority(&self) -> u8 {
+        if self.is_yom_tov() {
+            return 0;
+        }
+        if self.chanukah_candle_count().is_some() {
+            return 2;
+        }
+        match self {
+            Holiday::SukkotCholHamoedDay1 | Holiday::SukkotCholHamoedDay2 |
+            Holiday::SukkotCholHamoedDay3 | Holiday::SukkotCholHamoedDay4 |
+            Holiday::SukkotCholHamoedDay5 | Holiday::HoshanaRabbah |
+            Holiday::PesachCholHamoedDay1 | Holiday::PesachCholHamoedDay2 |
+            Holiday::PesachCholHamoedDay3 | Holiday::PesachCholHamoedDay4 |
+            Holiday::PesachCholHamoedDay5 |
+            Holiday::Purim | Holiday::ShushanPurim | Holiday::TaanitEsther |
+            Holiday::ShivaAsarBTammuz | Holiday::TishaBAv => 1,
+            Holiday::RoshChodesh => 3,
+            Holiday::TuBiShevat | Holiday::PesachSheni | Holiday::LagBaOmer |
+            Holiday::TuBAv | Holiday::YomHaShoah | Holiday::YomHaZikaron |
+            Holiday::YomHaAtzmaut | Holiday::YomYerushalayim => 4,
+            _ => 5,
+        }
+    }
+    
     /// Check if this is a Yom Tov (major holiday with work restrictions)
     pub fn is_yom_tov(&self) -> bool {
         matches!(self,
@@ -216,6 +227,18 @@ impl Holiday {
         )
     }
     
+    /// Check if this is one of the intermediate days of Sukkot or Pesach
+    pub fn is_chol_hamoed(&self) -> bool {
+        matches!(self,
+            Holiday::SukkotCholHamoedDay1 | Holiday::SukkotCholHamoedDay2 |
+            Holiday::SukkotCholHamoedDay3 | Holiday::SukkotCholHamoedDay4 |
+            Holiday::SukkotCholHamoedDay5 |
+            Holiday::PesachCholHamoedDay1 | Holiday::PesachCholHamoedDay2 |
+            Holiday::PesachCholHamoedDay3 | Holiday::PesachCholHamoedDay4 |
+            Holiday::PesachCholHamoedDay5
+        )
+    }
+    
     /// Check if this is a fast day
     pub fn is_fast_day(&self) -> bool {
         matches!(self,
@@ -225,17 +248,212 @@ impl Holiday {
     }
 }
 
+/// English and Hebrew names, one row per `Holiday` in declaration order
+static HOLIDAY_NAMES: [(Holiday, &str, &str); 95] = [
+    (Holiday::RoshHashanahDay1, "Rosh Hashanah (Day 1)", "ראש השנה (יום א׳)"),
+    (Holiday::RoshHashanahDay2, "Rosh Hashanah (Day 2)", "ראש השנה (יום ב׳)"),
+    (Holiday::YomKippur, "Yom Kippur", "יום כיפור"),
+    (Holiday::SukkotDay1, "Sukkot (Day 1)", "סוכות (יום א׳)"),
+    (Holiday::SukkotDay2, "Sukkot (Day 2)", "סוכות (יום ב׳)"),
+    (Holiday::SukkotCholHamoedDay1, "Sukkot (Chol HaMoed Day 1)", "סוכות (חול המועד יום א׳)"),
+    (Holiday::SukkotCholHamoedDay2, "Sukkot (Chol HaMoed Day 2)", "סוכות (חול המועד יום ב׳)"),
+    (Holiday::SukkotCholHamoedDay3, "Sukkot (Chol HaMoed Day 3)", "סוכות (חול המועד יום ג׳)"),
+    (Holiday::SukkotCholHamoedDay4, "Sukkot (Chol HaMoed Day 4)", "סוכות (חול המועד יום ד׳)"),
+    (Holiday::SukkotCholHamoedDay5, "Sukkot (Chol HaMoed Day 5)", "סוכות (חול המועד יום ה׳)"),
+    (Holiday::HoshanaRabbah, "Hoshana Rabbah", "הושענא רבה"),
+    (Holiday::SheminiAtzeret, "Shemini Atzeret", "שמיני עצרת"),
+    (Holiday::SimchatTorah, "Simchat Torah", "שמחת תורה"),
+    (Holiday::ChanukahDay1, "Chanukah (Day 1)", "חנוכה (יום א׳)"),
+    (Holiday::ChanukahDay2, "Chanukah (Day 2)", "חנוכה (יום ב׳)"),
+    (Holiday::ChanukahDay3, "Chanukah (Day 3)", "חנוכה (יום ג׳)"),
+    (Holiday::ChanukahDay4, "Chanukah (Day 4)", "חנוכה (יום ד׳)"),
+    (Holiday::ChanukahDay5, "Chanukah (Day 5)", "חנוכה (יום ה׳)"),
+    (Holiday::ChanukahDay6, "Chanukah (Day 6)", "חנוכה (יום ו׳)"),
+    (Holiday::ChanukahDay7, "Chanukah (Day 7)", "חנוכה (יום ז׳)"),
+    (Holiday::ChanukahDay8, "Chanukah (Day 8)", "חנוכה (יום ח׳)"),
+    (Holiday::TuBiShevat, "Tu B'Shevat", "ט״ו בשבט"),
+    (Holiday::TaanitEsther, "Ta'anit Esther", "תענית אסתר"),
+    (Holiday::Purim, "Purim", "פורים"),
+    (Holiday::ShushanPurim, "Shushan Purim", "שושן פורים"),
+    (Holiday::PesachDay1, "Pesach (Day 1)", "פסח (יום א׳)"),
+    (Holiday::PesachDay2, "Pesach (Day 2)", "פסח (יום ב׳)"),
+    (Holiday::PesachCholHamoedDay1, "Pesach (Chol HaMoed Day 1)", "פסח (חול המועד יום א׳)"),
+    (Holiday::PesachCholHamoedDay2, "Pesach (Chol HaMoed Day 2)", "פסח (חול המועד יום ב׳)"),
+    (Holiday::PesachCholHamoedDay3, "Pesach (Chol HaMoed Day 3)", "פסח (חול המועד יום ג׳)"),
+    (Holiday::PesachCholHamoedDay4, "Pesach (Chol HaMoed Day 4)", "פסח (חול המועד יום ד׳)"),
+    (Holiday::PesachCholHamoedDay5, "Pesach (Chol HaMoed Day 5)", "פסח (חול המועד יום ה׳)"),
+    (Holiday::PesachDay7, "Pesach (Day 7)", "שביעי של פסח"),
+    (Holiday::PesachDay8, "Pesach (Day 8)", "אחרון של פסח"),
+    (Holiday::PesachSheni, "Pesach Sheni", "פסח שני"),
+    (Holiday::OmerDay1, "Omer Day 1", "יום א׳ לעומר"),
+    (Holiday::OmerDay2, "Omer Day 2", "יום ב׳ לעומר"),
+    (Holiday::OmerDay3, "Omer Day 3", "יום ג׳ לעומר"),
+    (Holiday::OmerDay4, "Omer Day 4", "יום ד׳ לעומר"),
+    (Holiday::OmerDay5, "Omer Day 5", "יום ה׳ לעומר"),
+    (Holiday::OmerDay6, "Omer Day 6", "יום ו׳ לעומר"),
+    (Holiday::OmerDay7, "Omer Day 7", "יום ז׳ לעומר"),
+    (Holiday::OmerDay8, "Omer Day 8", "יום ח׳ לעומר"),
+    (Holiday::OmerDay9, "Omer Day 9", "יום ט׳ לעומר"),
+    (Holiday::OmerDay10, "Omer Day 10", "יום י׳ לעומר"),
+    (Holiday::OmerDay11, "Omer Day 11", "יום י״א לעומר"),
+    (Holiday::OmerDay12, "Omer Day 12", "יום י״ב לעומר"),
+    (Holiday::OmerDay13, "Omer Day 13", "יום י״ג לעומר"),
+    (Holiday::OmerDay14, "Omer Day 14", "יום י״ד לעומר"),
+    (Holiday::OmerDay15, "Omer Day 15", "יום ט״ו לעומר"),
+    (Holiday::OmerDay16, "Omer Day 16", "יום ט״ז לעומר"),
+    (Holiday::OmerDay17, "Omer Day 17", "יום י״ז לעומר"),
+    (Holiday::OmerDay18, "Omer Day 18", "יום י״ח לעומר"),
+    (Holiday::OmerDay19, "Omer Day 19", "יום י״ט לעומר"),
+    (Holiday::OmerDay20, "Omer Day 20", "יום כ׳ לעומר"),
+    (Holiday::OmerDay21, "Omer Day 21", "יום כ״א לעומר"),
+    (Holiday::OmerDay22, "Omer Day 22", "יום כ״ב לעומר"),
+    (Holiday::OmerDay23, "Omer Day 23", "יום כ״ג לעומר"),
+    (Holiday::OmerDay24, "Omer Day 24", "יום כ״ד לעומר"),
+    (Holiday::OmerDay25, "Omer Day 25", "יום כ״ה לעומר"),
+    (Holiday::OmerDay26, "Omer Day 26", "יום כ״ו לעומר"),
+    (Holiday::OmerDay27, "Omer Day 27", "יום כ״ז לעומר"),
+    (Holiday::OmerDay28, "Omer Day 28", "יום כ״ח לעומר"),
+    (Holiday::OmerDay29, "Omer Day 29", "יום כ״ט לעומר"),
+    (Holiday::OmerDay30, "Omer Day 30", "יום ל׳ לעומר"),
+    (Holiday::OmerDay31, "Omer Day 31", "יום ל״א לעומר"),
+    (Holiday::OmerDay32, "Omer Day 32", "יום ל״ב לעומר"),
+    (Holiday::OmerDay33, "Omer Day 33 (Lag BaOmer)", "יום ל״ג לעומר (ל״ג בעומר)"),
+    (Holiday::OmerDay34, "Omer Day 34", "יום ל״ד לעומר"),
+    (Holiday::OmerDay35, "Omer Day 35", "יום ל״ה לעומר"),
+    (Holiday::OmerDay36, "Omer Day 36", "יום ל״ו לעומר"),
+    (Holiday::OmerDay37, "Omer Day 37", "יום ל״ז לעומר"),
+    (Holiday::OmerDay38, "Omer Day 38", "יום ל״ח לעומר"),
+    (Holiday::OmerDay39, "Omer Day 39", "יום ל״ט לעומר"),
+    (Holiday::OmerDay40, "Omer Day 40", "יום מ׳ לעומר"),
+    (Holiday::OmerDay41, "Omer Day 41", "יום מ״א לעומר"),
+    (Holiday::OmerDay42, "Omer Day 42", "יום מ״ב לעומר"),
+    (Holiday::OmerDay43, "Omer Day 43", "יום מ״ג לעומר"),
+    (Holiday::OmerDay44, "Omer Day 44", "יום מ״ד לעומר"),
+    (Holiday::OmerDay45, "Omer Day 45", "יום מ״ה לעומר"),
+    (Holiday::OmerDay46, "Omer Day 46", "יום מ״ו לעומר"),
+    (Holiday::OmerDay47, "Omer Day 47", "יום מ״ז לעומר"),
+    (Holiday::OmerDay48, "Omer Day 48", "יום מ״ח לעומר"),
+    (Holiday::OmerDay49, "Omer Day 49", "יום מ״ט לעומר"),
+    (Holiday::LagBaOmer, "Lag BaOmer", "ל״ג בעומר"),
+    (Holiday::YomHaShoah, "Yom HaShoah", "יום השואה"),
+    (Holiday::YomHaZikaron, "Yom HaZikaron", "יום הזיכרון"),
+    (Holiday::YomHaAtzmaut, "Yom HaAtzmaut", "יום העצמאות"),
+    (Holiday::YomYerushalayim, "Yom Yerushalayim", "יום ירושלים"),
+    (Holiday::ShavuotDay1, "Shavuot (Day 1)", "שבועות (יום א׳)"),
+    (Holiday::ShavuotDay2, "Shavuot (Day 2)", "שבועות (יום ב׳)"),
+    (Holiday::ShivaAsarBTammuz, "Shiva Asar B'Tammuz", "שבעה עשר בתמוז"),
+    (Holiday::TishaBAv, "Tisha B'Av", "תשעה באב"),
+    (Holiday::TuBAv, "Tu B'Av", "ט״ו באב"),
+    (Holiday::RoshChodesh, "Rosh Chodesh", "ראש חודש"),
+];
+
+// Every variant has exactly one row, at the index of its discriminant
+const _: () = {
+    assert!(HOLIDAY_NAMES.len() == Holiday::RoshChodesh as usize + 1);
+    let mut i = 0;
+    while i < HOLIDAY_NAMES.len() {
+        assert!(HOLIDAY_NAMES[i].0 as usize == i);
+        i += 1;
+    }
+};
+
+/// Which festival calendar to follow
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum HolidayScheme {
+    /// Two days of Yom Tov for Sukkot, Shemini Atzeret, Pesach and Shavuot
+    #[default]
+    Diaspora,
+    /// One day of Yom Tov; Simchat Torah coincides with Shemini Atzeret
+    Israel,
+    /// One or two days chosen per festival, e.g. for a traveler between the two
+    Custom(FestivalDays),
+}
+
+impl HolidayScheme {
+    /// How many days of Yom Tov each festival has under this scheme
+    pub fn festival_days(self) -> FestivalDays {
+        match self {
+            HolidayScheme::Diaspora => FestivalDays::DIASPORA,
+            HolidayScheme::Israel => FestivalDays::ISRAEL,
+            HolidayScheme::Custom(days) => days,
+        }
+    }
+    
+    /// Whether the festival falling in `month` has a second day of Yom Tov
+    fn two_days_in(self, month: HebrewMonth) -> bool {
+        let days = self.festival_days();
+        match month {
+            HebrewMonth::Tishrei => days.sukkot,
+            HebrewMonth::Nisan => days.pesach,
+            HebrewMonth::Sivan => days.shavuot,
+            _ => true,
+        }
+    }
+}
+
+/// Second-day Yom Tov observance per festival (true = two days)
+///
+/// Sukkot covers Shemini Atzeret and Simchat Torah as well.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FestivalDays {
+    pub sukkot: bool,
+    pub pesach: bool,
+    pub shavuot: bool,
+}
+
+impl FestivalDays {
+    /// Two days for every festival
+    pub const DIASPORA: FestivalDays = FestivalDays { sukkot: true, pesach: true, shavuot: true };
+    /// One day for every festival
+    pub const ISRAEL: FestivalDays = FestivalDays { sukkot: false, pesach: false, shavuot: false };
+}
+
+impl Default for FestivalDays {
+    fn default() -> Self {
+        Self::DIASPORA
+    }
+}
+
+/// Prayer-service notes for a day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TefillaNotes {
+    /// Tachanun is said at Shacharit
+    pub tachanun: bool,
+    /// Tachanun is said at Mincha (omitted on the afternoon before a no-Tachanun day)
+    pub tachanun_at_mincha: bool,
+}
+
 /// Holiday calculator
 pub struct HolidayCalculator;
 
 impl HolidayCalculator {
-    /// Get all holidays for a specific Hebrew date
+    /// Largest window (end - start, in days) accepted by `holidays_between`
+    pub const MAX_SPAN_DAYS: i64 = 3 * 366;
+    
+    /// Get all holidays for a specific Hebrew date (diaspora calendar)
     pub fn get_holidays(date: &HebrewDate) -> Result<Vec<Holiday>, CalendarError> {
+        Self::get_holidays_for_scheme(date, HolidayScheme::Diaspora)
+    }
+    
+    /// Get all holidays for a specific Hebrew date under a festival scheme
+    ///
+    /// Fasts are reported on their calendar date even when it falls on
+    /// Shabbat; `hebrew_date_of` gives the day they are actually kept.
+    pub fn get_holidays_for_scheme(date: &HebrewDate, scheme: HolidayScheme) -> Result<Vec<Holiday>, CalendarError> {
         let mut holidays = Vec::new();
         
         // Check for major holidays
-        if let Some(holiday) = Self::get_major_holiday(date) {
+        let two_days = scheme.two_days_in(date.month);
+        let major = if two_days {
+            Self::get_major_holiday(date)
+        } else {
+            Self::get_israel_holiday(date)
+        };
+        if let Some(holiday) = major {
             holidays.push(holiday);
+            if !two_days && holiday == Holiday::SheminiAtzeret {
+                holidays.push(Holiday::SimchatTorah);
+            }
         }
         
         // Check for Chanukah
@@ -253,9 +471,306 @@ impl HolidayCalculator {
             holidays.push(Holiday::RoshChodesh);
         }
         
+        holidays.sort_by_key(Holiday::display_priority);
         Ok(holidays)
     }
     
+    /// List the Rosh Chodesh days of every month in a Hebrew year
+    ///
+    /// Each month is paired with its one or two Rosh Chodesh days: the 30th of
+    /// the prior month (when it has 30 days) and the 1st. Tishrei is excluded,
+    /// since its first day is observed as Rosh Hashanah rather than Rosh Chodesh.
+    pub fn rosh_chodesh_dates(hebrew_year: i32) -> Result<Vec<(HebrewMonth, Vec<HebrewDate>)>, CalendarError> {
+        let is_leap = DateConverter::is_hebrew_leap_year(hebrew_year);
+        let month_numbers = (8..=DateConverter::months_in_hebrew_year(hebrew_year)).chain(1..=6);
+        
+        let mut result = Vec::new();
+        for number in month_numbers {
+            let month = HebrewMonth::from_number(number, is_leap)?;
+            let (prev_year, prev_number) = DateConverter::previous_month(hebrew_year, month);
+            
+            let mut days = Vec::new();
+            if DateConverter::days_in_hebrew_month(prev_year, prev_number) == 30 {
+                let prev_month = HebrewMonth::from_number(
+                    prev_number,
+                    DateConverter::is_hebrew_leap_year(prev_year),
+                )?;
+                days.push(HebrewDate::new(prev_year, prev_month, 30));
+            }
+            days.push(HebrewDate::new(hebrew_year, month, 1));
+            
+            result.push((month, days));
+        }
+        
+        Ok(result)
+    }
+    
+    /// Find the first date on or after `from` on which `holiday` falls
+    ///
+    /// Scans up to two Hebrew years ahead so the search wraps into the next year.
+    pub fn next_occurrence(holiday: Holiday, from: NaiveDate) -> Result<NaiveDate, CalendarError> {
+        Self::scan_forward(from, |holidays| holidays.contains(&holiday))
+            .map(|(date, _)| date)
+    }
+    
+    /// Number of days from `from` until the next occurrence of `holiday` (0 if today)
+    pub fn days_until_next(holiday: Holiday, from: NaiveDate) -> Result<i64, CalendarError> {
+        let date = Self::next_occurrence(holiday, from)?;
+        Ok((date - from).num_days())
+    }
+    
+    /// The soonest Yom Tov on or after `from`
+    pub fn next_major_holiday(from: NaiveDate) -> Result<(NaiveDate, Holiday), CalendarError> {
+        let (date, holidays) = Self::scan_forward(from, |holidays| {
+            holidays.iter().any(|h| h.is_yom_tov())
+        })?;
+        let holiday = holidays.into_iter()
+            .find(|h| h.is_yom_tov())
+            .ok_or_else(|| CalendarError::CalculationError("No Yom Tov found".to_string()))?;
+        Ok((date, holiday))
+    }
+    
+    /// Walk forward day by day until `matches` accepts the day's holidays
+    fn scan_forward<F>(from: NaiveDate, matches: F) -> Result<(NaiveDate, Vec<Holiday>), CalendarError>
+    where
+        F: Fn(&[Holiday]) -> bool,
+    {
+        const MAX_SCAN_DAYS: u32 = 2 * 385;
+        
+        let mut date = from;
+        for _ in 0..MAX_SCAN_DAYS {
+            let holidays = Self::get_holidays(&DateConverter::gregorian_to_hebrew(date)?)?;
+            if matches(&holidays) {
+                return Ok((date, holidays));
+            }
+            date = date.succ_opt()
+                .ok_or_else(|| CalendarError::date_out_of_range(date))?;
+        }
+        
+        Err(CalendarError::CalculationError(
+            format!("No occurrence within {} days of {}", MAX_SCAN_DAYS, from)
+        ))
+    }
+    
+    /// The Hebrew date a holiday is observed on in a given Hebrew year
+    ///
+    /// Fasts falling on Shabbat are given on their observed day (Taanit Esther
+    /// moves back to Thursday, the summer fasts forward to Sunday), and the
+    /// modern Israeli days include their weekday adjustments. Holidays that
+    /// occur more than once (Rosh Chodesh) return the first occurrence.
+    ///
+    /// A postponed fast therefore differs from `get_holidays`, which lists it
+    /// on its calendar date (9 Av 5782 rather than the observed 10 Av).
+    pub fn hebrew_date_of(holiday: Holiday, year: i32, scheme: HolidayScheme) -> Result<HebrewDate, CalendarError> {
+        let on_shabbat = |month, day| -> Result<bool, CalendarError> {
+            Ok(DateConverter::weekday_of(year, month, day)? == Weekday::Sat)
+        };
+        
+        let (month, day) = match holiday {
+            Holiday::YomHaShoah => match DateConverter::weekday_of(year, HebrewMonth::Nisan, 27)? {
+                Weekday::Fri => (HebrewMonth::Nisan, 26),
+                Weekday::Sun => (HebrewMonth::Nisan, 28),
+                _ => (HebrewMonth::Nisan, 27),
+            },
+            Holiday::YomHaAtzmaut => (HebrewMonth::Iyar, Self::yom_haatzmaut_day(year)?),
+            Holiday::YomHaZikaron => (HebrewMonth::Iyar, Self::yom_haatzmaut_day(year)? - 1),
+            Holiday::YomYerushalayim => (HebrewMonth::Iyar, 28),
+            Holiday::TaanitEsther if on_shabbat(HebrewMonth::Adar, 13)? => (HebrewMonth::Adar, 11),
+            Holiday::ShivaAsarBTammuz if on_shabbat(HebrewMonth::Tammuz, 17)? => (HebrewMonth::Tammuz, 18),
+            Holiday::TishaBAv if on_shabbat(HebrewMonth::Av, 9)? => (HebrewMonth::Av, 10),
+            _ => return Self::scan_year(holiday, year, scheme),
+        };
+        Ok(HebrewDate::new(year, month, day))
+    }
+    
+    /// Day of Iyar Yom HaAtzmaut is observed, kept off Shabbat and its eve
+    /// and away from Motzei Shabbat
+    fn yom_haatzmaut_day(year: i32) -> Result<u8, CalendarError> {
+        Ok(match DateConverter::weekday_of(year, HebrewMonth::Iyar, 5)? {
+            Weekday::Fri => 4,
+            Weekday::Sat => 3,
+            Weekday::Mon => 6,
+            _ => 5,
+        })
+    }
+    
+    /// Find the first day of a Hebrew year on which a holiday appears
+    fn scan_year(holiday: Holiday, year: i32, scheme: HolidayScheme) -> Result<HebrewDate, CalendarError> {
+        let mut date = DateConverter::rd_to_gregorian(DateConverter::rosh_hashanah(year))?;
+        for _ in 0..DateConverter::days_in_hebrew_year(year) {
+            let hebrew = DateConverter::gregorian_to_hebrew(date)?;
+            if Self::get_holidays_for_scheme(&hebrew, scheme)?.contains(&holiday) {
+                return Ok(hebrew);
+            }
+            date = date.succ_opt()
+                .ok_or_else(|| CalendarError::date_out_of_range(date))?;
+        }
+        
+        Err(CalendarError::CalculationError(
+            format!("{} does not occur in {} under the {:?} scheme", holiday.name(), year, scheme)
+        ))
+    }
+    
+    /// The Omer count to say on the evening of a local date and time
+    ///
+    /// The count belongs to the Hebrew day that begins at nightfall, so after
+    /// tzeit this is the next day's count; before tzeit it is the count already
+    /// said the previous night.
+    pub fn omer_count_tonight(
+        datetime: NaiveDateTime,
+        location: &GeoLocation,
+    ) -> Result<Option<u8>, CalendarError> {
+        let hebrew = HebrewCalendar::hebrew_date_at_boundary(datetime, location, DayBoundary::Tzeit)?;
+        Ok(Self::omer_day_number(&hebrew))
+    }
+    
+    /// Get the prayer-service notes for a date
+    pub fn daily_tefilla_notes(date: &HebrewDate) -> Result<TefillaNotes, CalendarError> {
+        let tachanun = !Self::tachanun_omitted(date)?;
+        
+        let gregorian = date.gregorian()?;
+        let tomorrow = DateConverter::gregorian_to_hebrew(
+            gregorian.succ_opt().ok_or_else(|| CalendarError::date_out_of_range(gregorian))?
+        )?;
+        let tachanun_at_mincha = tachanun && !Self::tachanun_omitted(&tomorrow)?;
+        
+        Ok(TefillaNotes { tachanun, tachanun_at_mincha })
+    }
+    
+    /// Whether Tachanun is omitted for the whole day (Ashkenazi custom)
+    fn tachanun_omitted(date: &HebrewDate) -> Result<bool, CalendarError> {
+        // Shabbat
+        if date.try_day_of_week()? == Weekday::Sat {
+            return Ok(true);
+        }
+        
+        let omitted_by_date = match date.month {
+            // The whole month of Nisan
+            HebrewMonth::Nisan => true,
+            // Rosh Hashanah, and from Erev Yom Kippur through the end of Tishrei
+            HebrewMonth::Tishrei => matches!(date.day, 1 | 2 | 9..=30),
+            // Erev Rosh Hashanah
+            HebrewMonth::Elul => date.day == 29,
+            // Rosh Chodesh Sivan through the days of tashlumin after Shavuot
+            HebrewMonth::Sivan => date.day <= 12,
+            // Purim Katan and Shushan Purim Katan
+            HebrewMonth::AdarI => matches!(date.day, 14 | 15),
+            _ => false,
+        };
+        if omitted_by_date || date.day == 30 {
+            return Ok(true);
+        }
+        
+        // Tisha B'Av on the day it is observed, which may be 10 Av
+        if date.month == HebrewMonth::Av
+            && Self::hebrew_date_of(Holiday::TishaBAv, date.year, HolidayScheme::Diaspora)? == *date
+        {
+            return Ok(true);
+        }
+        
+        let holidays = Self::get_holidays(date)?;
+        Ok(holidays.iter().any(|h| {
+            h.chanukah_candle_count().is_some() || matches!(h,
+                Holiday::RoshChodesh | Holiday::TuBiShevat |
+                Holiday::Purim | Holiday::ShushanPurim |
+                Holiday::PesachSheni | Holiday::LagBaOmer |
+                Holiday::TuBAv
+            )
+        }))
+    }
+    
+    /// Gregorian date of the Rosh Hashanah that falls in a civil year
+    ///
+    /// Every civil year contains exactly one: that of Hebrew year `year + 3761`.
+    pub fn rosh_hashanah_for_gregorian_year(year: i32) -> Result<NaiveDate, CalendarError> {
+        DateConverter::rd_to_gregorian(DateConverter::rosh_hashanah(year + 3761))
+    }
+    
+    /// The Three Weeks: from the 17 Tammuz fast through the Tisha B'Av fast
+    ///
+    /// Both endpoints are the observed fasts, so a fast falling on Shabbat
+    /// (nidche) moves the endpoint to Sunday.
+    pub fn three_weeks_range(hebrew_year: i32) -> Result<(NaiveDate, NaiveDate), CalendarError> {
+        Ok((
+            Self::observed_fast(HebrewDate::new(hebrew_year, HebrewMonth::Tammuz, 17))?,
+            Self::observed_fast(HebrewDate::new(hebrew_year, HebrewMonth::Av, 9))?,
+        ))
+    }
+    
+    /// The Nine Days: from 1 Av through the observed Tisha B'Av fast
+    pub fn nine_days_range(hebrew_year: i32) -> Result<(NaiveDate, NaiveDate), CalendarError> {
+        Ok((
+            HebrewDate::new(hebrew_year, HebrewMonth::Av, 1).gregorian()?,
+            Self::observed_fast(HebrewDate::new(hebrew_year, HebrewMonth::Av, 9))?,
+        ))
+    }
+    
+    /// Check whether a Gregorian date falls within the Three Weeks
+    pub fn in_three_weeks(date: NaiveDate) -> Result<bool, CalendarError> {
+        let hebrew_year = DateConverter::gregorian_to_hebrew(date)?.year;
+        let (start, end) = Self::three_weeks_range(hebrew_year)?;
+        Ok((start..=end).contains(&date))
+    }
+    
+    /// Gregorian date a fast is observed on, deferred to Sunday from Shabbat
+    fn observed_fast(date: HebrewDate) -> Result<NaiveDate, CalendarError> {
+        let gregorian = date.gregorian()?;
+        if gregorian.weekday() == Weekday::Sat {
+            Ok(gregorian + Duration::days(1))
+        } else {
+            Ok(gregorian)
+        }
+    }
+    
+    /// List every holiday from `start` through `end` inclusive
+    ///
+    /// The window may span at most `MAX_SPAN_DAYS` and must lie in the supported range.
+    pub fn holidays_between(
+        start: NaiveDate,
+        end: NaiveDate,
+        scheme: HolidayScheme,
+    ) -> Result<Vec<(NaiveDate, Holiday)>, CalendarError> {
+        crate::HebrewCalendar::validate_range(start, end, Self::MAX_SPAN_DAYS)?;
+        crate::HebrewCalendar::check_supported_range(start)?;
+        crate::HebrewCalendar::check_supported_range(end)?;
+        
+        let mut result = Vec::new();
+        let mut date = start;
+        while date <= end {
+            let hebrew = DateConverter::gregorian_to_hebrew(date)?;
+            for holiday in Self::get_holidays_for_scheme(&hebrew, scheme)? {
+                result.push((date, holiday));
+            }
+            date = date.succ_opt()
+                .ok_or_else(|| CalendarError::date_out_of_range(date))?;
+        }
+        
+        Ok(result)
+    }
+    
+    /// Get the major holiday in Israel, where second festival days are not kept
+    fn get_israel_holiday(date: &HebrewDate) -> Option<Holiday> {
+        match (date.month, date.day) {
+            (HebrewMonth::Tishrei, 16..=20) => Some(match date.day {
+                16 => Holiday::SukkotCholHamoedDay1,
+                17 => Holiday::SukkotCholHamoedDay2,
+                18 => Holiday::SukkotCholHamoedDay3,
+                19 => Holiday::SukkotCholHamoedDay4,
+                _ => Holiday::SukkotCholHamoedDay5,
+            }),
+            (HebrewMonth::Tishrei, 23) => None,
+            (HebrewMonth::Nisan, 16..=20) => Some(match date.day {
+                16 => Holiday::PesachCholHamoedDay1,
+                17 => Holiday::PesachCholHamoedDay2,
+                18 => Holiday::PesachCholHamoedDay3,
+                19 => Holiday::PesachCholHamoedDay4,
+                _ => Holiday::PesachCholHamoedDay5,
+            }),
+            (HebrewMonth::Nisan, 22) | (HebrewMonth::Sivan, 7) => None,
+            _ => Self::get_major_holiday(date),
+        }
+    }
+    
     /// Get major holiday for the date (if any)
     fn get_major_holiday(date: &HebrewDate) -> Option<Holiday> {
         match date.month {
@@ -317,14 +832,10 @@ impl HolidayCalculator {
                 22 => Some(Holiday::PesachDay8),
                 _ => None,
             },
-            HebrewMonth::Iyar => {
-                if date.day == 18 {
-                    // Modern holidays - simplified
-                    // In reality, these move based on day of week
-                    None
-                } else {
-                    None
-                }
+            HebrewMonth::Iyar => match date.day {
+                14 => Some(Holiday::PesachSheni),
+                18 => Some(Holiday::LagBaOmer),
+                _ => None,
             },
             HebrewMonth::Sivan => match date.day {
                 6 => Some(Holiday::ShavuotDay1),
@@ -395,19 +906,26 @@ impl HolidayCalculator {
         )
     }
     
-    /// Get Omer day (if applicable)
-    fn get_omer_day(date: &HebrewDate) -> Option<Holiday> {
+    /// Day of the Omer count (1-49) for a Hebrew date, if any
+    ///
+    /// Each count is said on the night that begins its Hebrew date: day 1 on
+    /// the night entering 16 Nisan, day 49 on the night entering 5 Sivan. The
+    /// night entering 6 Sivan is Shavuot, which has no count.
+    fn omer_day_number(date: &HebrewDate) -> Option<u8> {
         // Omer starts on 16 Nisan and goes for 49 days
         let omer_day = match date.month {
-            HebrewMonth::Nisan if date.day >= 16 => (date.day - 15) as usize,
-            HebrewMonth::Iyar => (15 + date.day) as usize,
-            HebrewMonth::Sivan if date.day <= 5 => (44 + date.day) as usize,
+            HebrewMonth::Nisan if date.day >= 16 => date.day - 15,
+            HebrewMonth::Iyar => 15 + date.day,
+            HebrewMonth::Sivan if date.day <= 5 => 44 + date.day,
             _ => 0,
         };
         
-        if omer_day == 0 || omer_day > 49 {
-            return None;
-        }
+        (1..=49).contains(&omer_day).then_some(omer_day)
+    }
+    
+    /// Get Omer day (if applicable)
+    fn get_omer_day(date: &HebrewDate) -> Option<Holiday> {
+        let omer_day = Self::omer_day_number(date)?;
         
         // Map to Holiday enum
         match omer_day {
@@ -468,8 +986,7 @@ impl HolidayCalculator {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::calendar::{DateConverter, HebrewMonth};
-    use chrono::NaiveDate;
+    use crate::calendar::HebrewMonth;
     
     #[test]
     fn test_rosh_hashanah() {
@@ -506,6 +1023,27 @@ mod tests {
         assert!(holidays.contains(&Holiday::OmerDay33));
     }
     
+    #[test]
+    fn test_pesach_sheni() {
+        let hebrew = HebrewDate::new(5784, HebrewMonth::Iyar, 14);
+        let holidays = HolidayCalculator::get_holidays(&hebrew).unwrap();
+        assert!(holidays.contains(&Holiday::PesachSheni));
+        assert!(holidays.contains(&Holiday::OmerDay29));
+        assert!(!Holiday::PesachSheni.is_yom_tov());
+        assert!(!Holiday::PesachSheni.is_fast_day());
+        assert_eq!(Holiday::PesachSheni.name(), "Pesach Sheni");
+    }
+    
+    #[test]
+    fn test_lag_baomer() {
+        let hebrew = HebrewDate::new(5784, HebrewMonth::Iyar, 18);
+        let holidays = HolidayCalculator::get_holidays(&hebrew).unwrap();
+        assert!(holidays.contains(&Holiday::LagBaOmer));
+        assert!(holidays.contains(&Holiday::OmerDay33));
+        assert!(!Holiday::LagBaOmer.is_yom_tov());
+        assert!(!Holiday::LagBaOmer.is_fast_day());
+    }
+    
     #[test]
     fn test_chanukah() {
         let hebrew = HebrewDate::new(5784, HebrewMonth::Kislev, 25);
@@ -819,6 +1357,16 @@ mod tests {
 
     // === Trait methods ===
 
+    #[test]
+    fn test_is_chol_hamoed() {
+        assert!(Holiday::SukkotCholHamoedDay1.is_chol_hamoed());
+        assert!(Holiday::PesachCholHamoedDay4.is_chol_hamoed());
+        // Negatives
+        assert!(!Holiday::SukkotDay1.is_chol_hamoed());
+        assert!(!Holiday::HoshanaRabbah.is_chol_hamoed());
+        assert!(!Holiday::PesachDay7.is_chol_hamoed());
+    }
+
     #[test]
     fn test_is_yom_tov() {
         assert!(Holiday::RoshHashanahDay1.is_yom_tov());
@@ -864,4 +1412,320 @@ mod tests {
         assert_eq!(Holiday::TuBiShevat.name(), "Tu B'Shevat");
         assert_eq!(Holiday::OmerDay33.name(), "Omer Day 33 (Lag BaOmer)");
     }
+    
+    #[test]
+    fn test_next_major_holiday_from_late_summer() {
+        // Rosh Hashanah 5785 began on October 3, 2024
+        let from = NaiveDate::from_ymd_opt(2024, 8, 20).unwrap();
+        let (date, holiday) = HolidayCalculator::next_major_holiday(from).unwrap();
+        assert_eq!(holiday, Holiday::RoshHashanahDay1);
+        assert_eq!(date, NaiveDate::from_ymd_opt(2024, 10, 3).unwrap());
+    }
+    
+    #[test]
+    fn test_days_until_next_wraps_year() {
+        let from = NaiveDate::from_ymd_opt(2024, 8, 20).unwrap();
+        assert_eq!(HolidayCalculator::days_until_next(Holiday::RoshHashanahDay1, from).unwrap(), 44);
+        
+        // Pesach 5784 has passed, so the next one is in 5785 (April 13, 2025)
+        let days = HolidayCalculator::days_until_next(Holiday::PesachDay1, from).unwrap();
+        assert_eq!(from + chrono::Duration::days(days), NaiveDate::from_ymd_opt(2025, 4, 13).unwrap());
+        
+        // On the day itself the countdown is zero
+        let rh = NaiveDate::from_ymd_opt(2024, 10, 3).unwrap();
+        assert_eq!(HolidayCalculator::days_until_next(Holiday::RoshHashanahDay1, rh).unwrap(), 0);
+    }
+    
+    #[test]
+    fn test_chanukah_candle_count() {
+        assert_eq!(Holiday::ChanukahDay1.chanukah_candle_count(), Some(1));
+        assert_eq!(Holiday::ChanukahDay8.chanukah_candle_count(), Some(8));
+        assert_eq!(Holiday::PesachDay1.chanukah_candle_count(), None);
+        
+        // The display name no longer carries the count
+        assert_eq!(Holiday::ChanukahDay3.name(), "Chanukah (Day 3)");
+    }
+    
+    #[test]
+    fn test_rosh_chodesh_dates_5784() {
+        let months = HolidayCalculator::rosh_chodesh_dates(5784).unwrap();
+        
+        // Tishrei is excluded; 5784 is a leap year, so 12 months remain
+        assert_eq!(months.len(), 12);
+        assert!(months.iter().all(|(m, _)| *m != HebrewMonth::Tishrei));
+        
+        // Tishrei has 30 days, so Rosh Chodesh Cheshvan is two days
+        let (month, days) = &months[0];
+        assert_eq!(*month, HebrewMonth::Cheshvan);
+        assert_eq!(days, &vec![
+            HebrewDate::new(5784, HebrewMonth::Tishrei, 30),
+            HebrewDate::new(5784, HebrewMonth::Cheshvan, 1),
+        ]);
+        
+        // Iyar has 29 days, so Rosh Chodesh Sivan is one day
+        let (_, sivan) = months.iter().find(|(m, _)| *m == HebrewMonth::Sivan).unwrap();
+        assert_eq!(sivan, &vec![HebrewDate::new(5784, HebrewMonth::Sivan, 1)]);
+    }
+
+    #[test]
+    fn test_holidays_between_spanning_pesach() {
+        let start = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 5, 15).unwrap();
+        let day = |m, d| NaiveDate::from_ymd_opt(2024, m, d).unwrap();
+        
+        let diaspora = HolidayCalculator::holidays_between(start, end, HolidayScheme::Diaspora).unwrap();
+        assert!(diaspora.contains(&(day(3, 24), Holiday::Purim)));
+        assert!(diaspora.contains(&(day(4, 23), Holiday::PesachDay1)));
+        assert!(diaspora.contains(&(day(4, 30), Holiday::PesachDay8)));
+        assert!(diaspora.iter().all(|(d, _)| *d >= start && *d <= end));
+        
+        // In Israel Pesach ends after the seventh day
+        let israel = HolidayCalculator::holidays_between(start, end, HolidayScheme::Israel).unwrap();
+        assert!(israel.contains(&(day(4, 24), Holiday::PesachCholHamoedDay1)));
+        assert!(israel.contains(&(day(4, 29), Holiday::PesachDay7)));
+        assert!(!israel.iter().any(|(_, h)| *h == Holiday::PesachDay8));
+    }
+    
+    #[test]
+    fn test_holidays_between_rejects_long_span() {
+        let start = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2030, 1, 1).unwrap();
+        assert!(matches!(
+            HolidayCalculator::holidays_between(start, end, HolidayScheme::Diaspora),
+            Err(CalendarError::RangeTooLarge { .. })
+        ));
+    }
+    
+    #[test]
+    fn test_holiday_name_in() {
+        assert_eq!(Holiday::PesachDay1.name_in(Language::English), "Pesach (Day 1)");
+        assert_eq!(Holiday::PesachDay1.name_in(Language::Hebrew), "פסח (יום א׳)");
+        assert_eq!(Holiday::PesachCholHamoedDay2.name_in(Language::Hebrew), "פסח (חול המועד יום ב׳)");
+        assert_eq!(Holiday::PesachDay1.name(), Holiday::PesachDay1.name_in(Language::English));
+        assert_eq!(Holiday::OmerDay33.name_in(Language::Hebrew), "יום ל״ג לעומר (ל״ג בעומר)");
+        assert_eq!(Holiday::RoshChodesh.name_in(Language::Hebrew), "ראש חודש");
+    }
+    
+    #[test]
+    fn test_custom_scheme_mixes_festival_days() {
+        // Diaspora Pesach, Israeli Sukkot and Shavuot
+        let scheme = HolidayScheme::Custom(FestivalDays { sukkot: false, pesach: true, shavuot: false });
+        let start = NaiveDate::from_ymd_opt(2024, 10, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 6, 30).unwrap();
+        let holidays = HolidayCalculator::holidays_between(start, end, scheme).unwrap();
+        let yom_tov_days = |month: u32, from_day: u32| {
+            let mut days: Vec<NaiveDate> = holidays.iter()
+                .filter(|(d, h)| d.month() == month && d.day() >= from_day && h.is_yom_tov())
+                .map(|(d, _)| *d)
+                .collect();
+            days.dedup();
+            days.len()
+        };
+        
+        // Sukkot 5785: first day and Shemini Atzeret, which is also Simchat Torah
+        assert_eq!(yom_tov_days(10, 15), 2);
+        assert!(holidays.contains(&(NaiveDate::from_ymd_opt(2024, 10, 24).unwrap(), Holiday::SimchatTorah)));
+        // Pesach 5785: two days at each end; Shavuot: one day
+        assert_eq!(yom_tov_days(4, 1), 4);
+        assert_eq!(yom_tov_days(6, 1), 1);
+        
+        assert_eq!(HolidayScheme::Custom(FestivalDays::ISRAEL).festival_days(), HolidayScheme::Israel.festival_days());
+    }
+    
+    #[test]
+    fn test_israel_simchat_torah_on_shemini_atzeret() {
+        let date = HebrewDate::new(5785, HebrewMonth::Tishrei, 22);
+        let holidays = HolidayCalculator::get_holidays_for_scheme(&date, HolidayScheme::Israel).unwrap();
+        assert!(holidays.contains(&Holiday::SheminiAtzeret));
+        assert!(holidays.contains(&Holiday::SimchatTorah));
+        
+        let next = HebrewDate::new(5785, HebrewMonth::Tishrei, 23);
+        assert!(HolidayCalculator::get_holidays_for_scheme(&next, HolidayScheme::Israel).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_holidays_sorted_by_display_priority() {
+        // 1 Teves 5784: Chanukah and Rosh Chodesh
+        let date = HebrewDate::new(5784, HebrewMonth::Teves, 1);
+        let holidays = HolidayCalculator::get_holidays(&date).unwrap();
+        assert_eq!(holidays.len(), 2);
+        assert!(holidays[0].chanukah_candle_count().is_some());
+        assert_eq!(holidays[1], Holiday::RoshChodesh);
+        
+        // 1 Iyar 5784: Rosh Chodesh leads the Omer count
+        let date = HebrewDate::new(5784, HebrewMonth::Iyar, 1);
+        let holidays = HolidayCalculator::get_holidays(&date).unwrap();
+        assert_eq!(holidays, vec![Holiday::RoshChodesh, Holiday::OmerDay16]);
+    }
+
+    #[test]
+    fn test_omer_count_tonight() {
+        // May 1, 2024 is 23 Nisan 5784 (Omer day 8); that night begins day 9
+        let jerusalem = GeoLocation::jerusalem();
+        let date = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+        let afternoon = date.and_hms_opt(15, 0, 0).unwrap();
+        let night = date.and_hms_opt(21, 0, 0).unwrap();
+        assert_eq!(HolidayCalculator::omer_count_tonight(afternoon, &jerusalem).unwrap(), Some(8));
+        assert_eq!(HolidayCalculator::omer_count_tonight(night, &jerusalem).unwrap(), Some(9));
+        
+        // Outside the Omer period
+        let winter = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(21, 0, 0).unwrap();
+        assert_eq!(HolidayCalculator::omer_count_tonight(winter, &jerusalem).unwrap(), None);
+    }
+
+    #[test]
+    fn test_rosh_hashanah_for_gregorian_year() {
+        let rosh_hashanah = HolidayCalculator::rosh_hashanah_for_gregorian_year;
+        assert_eq!(rosh_hashanah(2023).unwrap(), NaiveDate::from_ymd_opt(2023, 9, 16).unwrap());
+        assert_eq!(rosh_hashanah(2024).unwrap(), NaiveDate::from_ymd_opt(2024, 10, 3).unwrap());
+        
+        for year in 1900..2050 {
+            let date = rosh_hashanah(year).unwrap();
+            assert_eq!(date.year(), year);
+            assert_eq!(HolidayCalculator::get_holidays(&DateConverter::gregorian_to_hebrew(date).unwrap()).unwrap()[0], Holiday::RoshHashanahDay1);
+        }
+    }
+    
+    #[test]
+    fn test_three_weeks_and_nine_days() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        
+        // 5784: 17 Tammuz on Tuesday, July 23, 2024; 9 Av on Tuesday, August 13
+        assert_eq!(HolidayCalculator::three_weeks_range(5784).unwrap(), (ymd(2024, 7, 23), ymd(2024, 8, 13)));
+        assert_eq!(HolidayCalculator::nine_days_range(5784).unwrap(), (ymd(2024, 8, 5), ymd(2024, 8, 13)));
+        assert!(HolidayCalculator::in_three_weeks(ymd(2024, 8, 1)).unwrap());
+        assert!(!HolidayCalculator::in_three_weeks(ymd(2024, 7, 22)).unwrap());
+        assert!(!HolidayCalculator::in_three_weeks(ymd(2024, 8, 14)).unwrap());
+        
+        // 5782: both fasts fall on Shabbat and are observed on Sunday
+        assert_eq!(HolidayCalculator::three_weeks_range(5782).unwrap(), (ymd(2022, 7, 17), ymd(2022, 8, 7)));
+        assert_eq!(HolidayCalculator::nine_days_range(5782).unwrap(), (ymd(2022, 7, 29), ymd(2022, 8, 7)));
+        assert!(HolidayCalculator::in_three_weeks(ymd(2022, 8, 7)).unwrap());
+        assert!(!HolidayCalculator::in_three_weeks(ymd(2022, 7, 16)).unwrap());
+    }
+
+    #[test]
+    fn test_omer_final_count_before_shavuot() {
+        // 5 Sivan 5784 is Tuesday, June 11, 2024; Shavuot begins that evening
+        let jerusalem = GeoLocation::jerusalem();
+        let ymd_hm = |d, h| NaiveDate::from_ymd_opt(2024, 6, d).unwrap().and_hms_opt(h, 0, 0).unwrap();
+        
+        // The 49th count is said on the night entering 5 Sivan
+        assert_eq!(HolidayCalculator::omer_count_tonight(ymd_hm(10, 21), &jerusalem).unwrap(), Some(49));
+        assert_eq!(HolidayCalculator::omer_count_tonight(ymd_hm(11, 15), &jerusalem).unwrap(), Some(49));
+        
+        // The night entering 6 Sivan, and 6 Sivan itself, have no count
+        assert_eq!(HolidayCalculator::omer_count_tonight(ymd_hm(11, 21), &jerusalem).unwrap(), None);
+        let shavuot = HebrewDate::new(5784, HebrewMonth::Sivan, 6);
+        assert!(HolidayCalculator::get_holidays(&shavuot).unwrap().iter().all(|h| !h.name().starts_with("Omer")));
+    }
+
+    #[test]
+    fn test_fixed_hebrew_date() {
+        assert_eq!(Holiday::YomKippur.fixed_hebrew_date(), Some((HebrewMonth::Tishrei, 10)));
+        assert_eq!(Holiday::TuBiShevat.fixed_hebrew_date(), Some((HebrewMonth::Shevat, 15)));
+        assert_eq!(Holiday::ShavuotDay1.fixed_hebrew_date(), Some((HebrewMonth::Sivan, 6)));
+        assert_eq!(Holiday::ChanukahDay1.fixed_hebrew_date(), None);
+        assert_eq!(Holiday::OmerDay10.fixed_hebrew_date(), None);
+        assert_eq!(Holiday::TishaBAv.fixed_hebrew_date(), None);
+        
+        // Agrees with the Diaspora holiday placement
+        for holiday in [Holiday::RoshHashanahDay2, Holiday::HoshanaRabbah, Holiday::Purim, Holiday::PesachDay8] {
+            let (month, day) = holiday.fixed_hebrew_date().unwrap();
+            let date = HebrewDate::new(5785, month, day);
+            assert!(HolidayCalculator::get_holidays(&date).unwrap().contains(&holiday));
+        }
+    }
+
+    #[test]
+    fn test_hebrew_date_of() {
+        use HolidayScheme::*;
+        
+        // Purim falls in Adar II in a leap year
+        assert_eq!(
+            HolidayCalculator::hebrew_date_of(Holiday::Purim, 5784, Diaspora).unwrap(),
+            HebrewDate::new(5784, HebrewMonth::Adar, 14)
+        );
+        
+        // 9 Av 5782 was Shabbat; the fast was observed on 10 Av
+        assert_eq!(
+            HolidayCalculator::hebrew_date_of(Holiday::TishaBAv, 5782, Diaspora).unwrap(),
+            HebrewDate::new(5782, HebrewMonth::Av, 10)
+        );
+        // get_holidays keeps it on the calendar date
+        let nominal = HebrewDate::new(5782, HebrewMonth::Av, 9);
+        assert!(HolidayCalculator::get_holidays(&nominal).unwrap().contains(&Holiday::TishaBAv));
+        assert_eq!(
+            HolidayCalculator::hebrew_date_of(Holiday::TishaBAv, 5784, Diaspora).unwrap(),
+            HebrewDate::new(5784, HebrewMonth::Av, 9)
+        );
+        
+        // 5 Iyar 5784 was a Monday, so Yom HaAtzmaut moved to Tuesday
+        assert_eq!(
+            HolidayCalculator::hebrew_date_of(Holiday::YomHaAtzmaut, 5784, Israel).unwrap(),
+            HebrewDate::new(5784, HebrewMonth::Iyar, 6)
+        );
+        
+        // Scheme-dependent placement
+        assert_eq!(
+            HolidayCalculator::hebrew_date_of(Holiday::SimchatTorah, 5785, Israel).unwrap().day,
+            22
+        );
+        assert_eq!(
+            HolidayCalculator::hebrew_date_of(Holiday::SimchatTorah, 5785, Diaspora).unwrap().day,
+            23
+        );
+        assert!(HolidayCalculator::hebrew_date_of(Holiday::ShavuotDay2, 5785, Israel).is_err());
+    }
+
+    #[test]
+    fn test_no_tachanun_in_nisan() {
+        // 8 Nisan 5784 = Tuesday, April 16, 2024
+        let date = HebrewDate::new(5784, HebrewMonth::Nisan, 8);
+        let notes = HolidayCalculator::daily_tefilla_notes(&date).unwrap();
+        assert!(!notes.tachanun);
+        assert!(!notes.tachanun_at_mincha);
+    }
+    
+    #[test]
+    fn test_tachanun_on_plain_cheshvan_day() {
+        // 10 Cheshvan 5785 = Monday, November 11, 2024
+        let date = HebrewDate::new(5785, HebrewMonth::Cheshvan, 10);
+        let notes = HolidayCalculator::daily_tefilla_notes(&date).unwrap();
+        assert!(notes.tachanun);
+        assert!(notes.tachanun_at_mincha);
+    }
+    
+    #[test]
+    fn test_tachanun_windows() {
+        let said = |month, day| {
+            HolidayCalculator::daily_tefilla_notes(&HebrewDate::new(5785, month, day)).unwrap().tachanun
+        };
+        
+        // Between Yom Kippur and Sukkot, and the days after Shavuot
+        assert!(!said(HebrewMonth::Tishrei, 12));
+        assert!(!said(HebrewMonth::Sivan, 10));
+        assert!(said(HebrewMonth::Sivan, 13));
+        
+        // Said during the Ten Days of Repentance, but not on Erev Yom Kippur
+        assert!(said(HebrewMonth::Tishrei, 5));
+        assert!(!said(HebrewMonth::Tishrei, 9));
+        
+        // Omitted at Mincha the afternoon before Pesach Sheni (Sunday 13 Iyar 5785)
+        let erev = HolidayCalculator::daily_tefilla_notes(&HebrewDate::new(5785, HebrewMonth::Iyar, 13)).unwrap();
+        assert!(erev.tachanun);
+        assert!(!erev.tachanun_at_mincha);
+    }
+    
+    #[test]
+    fn test_no_tachanun_on_postponed_tisha_bav() {
+        // 9 Av 5782 fell on Shabbat; the fast was Sunday 10 Av, August 7, 2022
+        let fast = HolidayCalculator::daily_tefilla_notes(&HebrewDate::new(5782, HebrewMonth::Av, 10)).unwrap();
+        assert!(!fast.tachanun);
+        assert!(!fast.tachanun_at_mincha);
+        
+        // In 5784 the fast stayed on 9 Av, so 10 Av is an ordinary day
+        let after = HolidayCalculator::daily_tefilla_notes(&HebrewDate::new(5784, HebrewMonth::Av, 10)).unwrap();
+        assert!(after.tachanun);
+    }
 }
@@ -2,26 +2,41 @@
 //! 
 //! Pure logic for Hebrew-Gregorian calendar conversion and Zmanim calculations.
 //! Supports the proleptic fixed Hebrew calendar from 0 AD (1 BCE) to 2050 AD.
+//!
+//! Without the default `std` feature the crate is `no_std` and needs only
+//! `alloc`, so the calendar and solar math can run on microcontrollers.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
 pub mod calendar;
 pub mod zmanim;
 pub mod holidays;
 pub mod parsha;
+pub mod gematria;
 
-pub use calendar::{DateConverter, HebrewDate, GregorianDate};
-pub use zmanim::{ZmanimCalculator, Zmanim, GeoLocation};
-pub use holidays::{Holiday, HolidayCalculator};
-pub use parsha::{Parsha, ParshaCalculator};
+pub use calendar::{DateConverter, HebrewDate, HebrewYearInfo, GregorianDate, KiddushLevanaStart, Season, TransliterationStyle, YearNumbering, YearType};
+#[cfg(feature = "testutil")]
+pub use calendar::roundtrip_check;
+pub use zmanim::{ErevPesachTimes, FastStart, MgaBasis, SolarDebug, ZmanThreshold, ZmanimCalculator, ZmanimConfig, Zmanim, ZmanimDateTimes, ZmanimDayOffsets, ZmanimReliability, GeoLocation};
+pub use holidays::{FestivalDays, Holiday, HolidayCalculator, HolidayScheme, TefillaNotes};
+pub use parsha::{Parsha, ParshaCalculator, ShabbatContext, SpecialShabbat, TriennialReading};
 
-use chrono::{Datelike, NaiveDate};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use calendar::HebrewMonth;
+use chrono::format::{Parsed, StrftimeItems};
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 /// Errors that can occur in the hebrew_core library
 #[derive(Error, Debug, Clone, PartialEq)]
 pub enum CalendarError {
-    #[error("Date out of supported range (0 AD to 2050 AD): {0}")]
-    DateOutOfRange(String),
+    #[error("Date {date} is out of the supported range ({min} to {max})")]
+    DateOutOfRange { date: String, min: String, max: String },
     
     #[error("Invalid date format: {0}")]
     InvalidDateFormat(String),
@@ -32,10 +47,64 @@ pub enum CalendarError {
     #[error("Invalid longitude: {0}. Must be between -180 and 180.")]
     InvalidLongitude(f64),
     
+    #[error("Invalid timezone: {0}. Must be an IANA zone name such as America/New_York.")]
+    InvalidTimezone(String),
+    
+    #[error("Invalid elevation: {0}. Must be between -500 and 9000 meters.")]
+    InvalidElevation(f64),
+    
+    #[error("Invalid JSON: {0}")]
+    InvalidJson(String),
+    
+    #[error("Zmanim are undefined at latitude {0}: the sun does not rise and set daily at the pole")]
+    PolarLocation(f64),
+    
+    #[error("Date range too large (max {max} days, requested {requested})")]
+    RangeTooLarge { requested: i64, max: i64 },
+    
+    #[error("Invalid date range: end {end} is before start {start}")]
+    InvalidRange { start: NaiveDate, end: NaiveDate },
+    
     #[error("Calculation error: {0}")]
     CalculationError(String),
 }
 
+impl CalendarError {
+    /// Stable machine-readable code, e.g. "DATE_OUT_OF_RANGE"
+    pub fn code(&self) -> &'static str {
+        match self {
+            CalendarError::DateOutOfRange { .. } => "DATE_OUT_OF_RANGE",
+            CalendarError::InvalidDateFormat(_) => "INVALID_DATE_FORMAT",
+            CalendarError::InvalidLatitude(_) => "INVALID_LATITUDE",
+            CalendarError::InvalidLongitude(_) => "INVALID_LONGITUDE",
+            CalendarError::InvalidTimezone(_) => "INVALID_TIMEZONE",
+            CalendarError::InvalidElevation(_) => "INVALID_ELEVATION",
+            CalendarError::InvalidJson(_) => "INVALID_JSON",
+            CalendarError::PolarLocation(_) => "POLAR_LOCATION",
+            CalendarError::RangeTooLarge { .. } => "RANGE_TOO_LARGE",
+            CalendarError::InvalidRange { .. } => "INVALID_RANGE",
+            CalendarError::CalculationError(_) => "CALCULATION_ERROR",
+        }
+    }
+    
+    /// HTTP status for this error: 400 for bad input, 500 for calculation failures
+    pub fn http_status(&self) -> u16 {
+        match self {
+            CalendarError::CalculationError(_) => 500,
+            _ => 400,
+        }
+    }
+    
+    /// A `DateOutOfRange` error carrying the supported bounds
+    pub fn date_out_of_range(date: impl core::fmt::Display) -> Self {
+        CalendarError::DateOutOfRange {
+            date: date.to_string(),
+            min: HebrewCalendar::MIN_DATE.to_string(),
+            max: HebrewCalendar::MAX_DATE.to_string(),
+        }
+    }
+}
+
 /// Complete daily calendar data
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct DailyData {
@@ -53,35 +122,161 @@ pub struct DailyData {
     pub candle_lighting: Option<String>,
     /// Whether this is a Shabbat or Yom Tov
     pub is_yom_tov: bool,
+    /// Whether an eruv tavshilin is made today (erev a Yom Tov that runs into Shabbat)
+    pub requires_eruv_tavshilin: bool,
+    /// When to light Chanukah candles this evening (if location provided and tonight is a Chanukah night)
+    #[serde(default)]
+    pub chanukah_lighting_time: Option<String>,
+    /// Plag hamincha, for "early Shabbat" on Fridays past the location's threshold
+    #[serde(default)]
+    pub early_shabbat_candle_lighting: Option<String>,
+    /// Which of Shmuel's tekufot the date falls in
+    #[serde(default)]
+    pub season: Option<Season>,
+}
+
+impl DailyData {
+    /// Compare the calendar content, ignoring everything that depends on location
+    ///
+    /// Zmanim, candle lighting, Chanukah lighting and early Shabbat are skipped.
+    pub fn calendar_eq(&self, other: &DailyData) -> bool {
+        self.gregorian == other.gregorian
+            && self.hebrew == other.hebrew
+            && self.parsha == other.parsha
+            && self.holidays == other.holidays
+            && self.is_yom_tov == other.is_yom_tov
+            && self.requires_eruv_tavshilin == other.requires_eruv_tavshilin
+            && self.season == other.season
+    }
+}
+
+#[cfg(feature = "binary")]
+impl DailyData {
+    /// Encode as compact postcard bytes
+    pub fn to_bytes(&self) -> Result<Vec<u8>, CalendarError> {
+        postcard::to_allocvec(self).map_err(|e| CalendarError::CalculationError(e.to_string()))
+    }
+    
+    /// Decode from bytes produced by `to_bytes`
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, CalendarError> {
+        postcard::from_bytes(bytes).map_err(|e| CalendarError::CalculationError(e.to_string()))
+    }
+}
+
+/// How a parsed calendar date string is interpreted
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CalendarSystem {
+    /// Proleptic Gregorian for all dates
+    #[default]
+    Gregorian,
+    /// Julian calendar for all dates
+    Julian,
+    /// Julian before the Gregorian reform (1582-10-15), Gregorian from then on
+    AutoByDate,
+}
+
+/// Display language for names
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    English,
+    Hebrew,
+}
+
+/// Gender for bar/bat mitzvah age (13 for a boy, 12 for a girl)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Gender {
+    Male,
+    Female,
+}
+
+/// Holy-day flags for one civil day, for shading a month grid
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayFlags {
+    pub date: NaiveDate,
+    pub is_shabbat: bool,
+    pub is_yom_tov: bool,
+    pub is_rosh_chodesh: bool,
+    pub is_fast: bool,
+    pub is_chol_hamoed: bool,
+    /// The month being ushered in, on either day of Rosh Chodesh
+    #[serde(default)]
+    pub rosh_chodesh_month: Option<HebrewMonth>,
+}
+
+/// Candle lighting, parsha and havdalah for one Shabbat
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShabbatTimes {
+    /// The Friday candles are lit on
+    pub friday: NaiveDate,
+    pub candle_lighting: Option<String>,
+    pub parsha: Parsha,
+    /// Saturday night
+    pub havdalah: Option<String>,
+}
+
+/// When the Hebrew day rolls over in the evening
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DayBoundary {
+    /// At sunset (shkiah)
+    #[default]
+    Sunset,
+    /// At nightfall (tzeit hakochavim)
+    Tzeit,
 }
 
 /// Main entry point for calendar calculations
 pub struct HebrewCalendar;
 
 impl HebrewCalendar {
+    /// Earliest supported date (1 BCE, ISO year 0)
+    pub const MIN_DATE: NaiveDate = match NaiveDate::from_ymd_opt(0, 1, 1) {
+        Some(date) => date,
+        None => panic!("invalid MIN_DATE"),
+    };
+    
+    /// Latest supported date
+    pub const MAX_DATE: NaiveDate = match NaiveDate::from_ymd_opt(2050, 12, 31) {
+        Some(date) => date,
+        None => panic!("invalid MAX_DATE"),
+    };
+    
+    /// Largest span (end - start, in days) accepted by the range endpoints
+    pub const MAX_RANGE_DAYS: i64 = 366;
+    
     /// Calculate complete calendar data for a specific date and location
+    ///
+    /// `date`'s year, month and day are read in `system`, so under `Julian`
+    /// 1492-10-12 is the day Gregorian calls 1492-10-21. Julian dates that have
+    /// no Gregorian counterpart (1500-02-29) must go through `parse_date_in`.
     pub fn calculate_day(
+        date: NaiveDate,
+        system: CalendarSystem,
+        location: Option<GeoLocation>,
+        candle_offset_minutes: i64,
+    ) -> Result<DailyData, CalendarError> {
+        let date = Self::resolve_date((date.year(), date.month(), date.day()), system)?;
+        Self::check_supported_range(date)?;
+        Self::calculate_day_unchecked(date, location, candle_offset_minutes)
+    }
+    
+    /// Like `calculate_day`, but without the `MIN_DATE`..`MAX_DATE` range check
+    ///
+    /// Intended for historical research (e.g. Second Temple era dates). Conversions
+    /// that cannot be performed still return an error. Zmanim for ancient dates are
+    /// only approximate: the solar model and historical time zones lose accuracy
+    /// far from the present.
+    pub fn calculate_day_unchecked(
         date: NaiveDate,
         location: Option<GeoLocation>,
         candle_offset_minutes: i64,
     ) -> Result<DailyData, CalendarError> {
-        // Validate date range (0 AD to 2050 AD)
-        let min_date = NaiveDate::from_ymd_opt(0, 1, 1)
-            .ok_or_else(|| CalendarError::DateOutOfRange("Cannot create min date".to_string()))?;
-        let max_date = NaiveDate::from_ymd_opt(2050, 12, 31)
-            .ok_or_else(|| CalendarError::DateOutOfRange("Cannot create max date".to_string()))?;
-        
-        if date < min_date || date > max_date {
-            return Err(CalendarError::DateOutOfRange(
-                format!("Date {} is outside supported range", date)
-            ));
-        }
-        
         // Convert to Hebrew date
         let hebrew = DateConverter::gregorian_to_hebrew(date)?;
+        let weekday = hebrew.try_day_of_week()?;
         
         // Get parsha
-        let parsha = if hebrew.day_of_week() == 6 { // Saturday (0=Sunday, 6=Saturday)
+        let parsha = if weekday == Weekday::Sat {
             Some(ParshaCalculator::get_parsha(&hebrew)?)
         } else {
             None
@@ -89,25 +284,42 @@ impl HebrewCalendar {
         
         // Get holidays
         let holidays = HolidayCalculator::get_holidays(&hebrew)?;
-        let is_yom_tov = holidays.iter().any(|h| h.is_yom_tov()) || hebrew.day_of_week() == 6; // Shabbat
+        let is_yom_tov = holidays.iter().any(|h| h.is_yom_tov()) || weekday == Weekday::Sat;
         
         // Calculate zmanim if location provided
-        let (zmanim, candle_lighting) = if let Some(loc) = location {
+        let (zmanim, candle_lighting, chanukah_lighting_time, early_shabbat_candle_lighting) = if let Some(loc) = location {
+            let early_threshold = loc.early_shabbat_threshold;
             let calc = ZmanimCalculator::new(loc);
             let z = calc.calculate(date)?;
             
             // Calculate candle lighting
-            let candle = if is_yom_tov || hebrew.day_of_week() == 5 { // Friday (day_of_week 5) or erev Yom Tov
+            let candle = if is_yom_tov || weekday == Weekday::Fri { // Friday or erev Yom Tov
                 calc.candle_lighting(&z, candle_offset_minutes)?
             } else {
                 None
             };
             
-            (Some(z), candle)
+            let chanukah = Self::chanukah_lighting_time(date, &z, candle.as_ref())?;
+            
+            // Early Shabbat at plag when Friday candle lighting is later than the threshold
+            let late_candles = match (early_threshold, &candle) {
+                (Some(threshold), Some(candle)) => NaiveTime::parse_from_str(candle, "%H:%M")
+                    .map_err(|e| CalendarError::CalculationError(e.to_string()))? > threshold,
+                _ => false,
+            };
+            let early = if weekday == Weekday::Fri && late_candles {
+                z.plag_hamincha.clone()
+            } else {
+                None
+            };
+            
+            (Some(z), candle, chanukah, early)
         } else {
-            (None, None)
+            (None, None, None, None)
         };
         
+        let requires_eruv_tavshilin = Self::requires_eruv_tavshilin(date)?;
+        
         Ok(DailyData {
             gregorian: GregorianDate::from(date),
             hebrew,
@@ -116,36 +328,475 @@ impl HebrewCalendar {
             zmanim,
             candle_lighting,
             is_yom_tov,
+            requires_eruv_tavshilin,
+            chanukah_lighting_time,
+            early_shabbat_candle_lighting,
+            season: Some(DateConverter::tekufa_of(date)),
         })
     }
     
-    /// Parse an ISO date string (supports year 0 for 1 BCE)
+    /// Chanukah lighting time for the evening of `date`, if tonight is a Chanukah night
+    ///
+    /// On Friday the lights are lit before the Shabbat candles; on Saturday night
+    /// after nightfall (and Havdalah). Other evenings give the window from plag
+    /// hamincha to sunset as "HH:MM-HH:MM".
+    fn chanukah_lighting_time(
+        date: NaiveDate,
+        zmanim: &Zmanim,
+        candle_lighting: Option<&String>,
+    ) -> Result<Option<String>, CalendarError> {
+        let tomorrow = date.succ_opt()
+            .ok_or_else(|| CalendarError::date_out_of_range(date))?;
+        let tomorrow_holidays = HolidayCalculator::get_holidays(&DateConverter::gregorian_to_hebrew(tomorrow)?)?;
+        if !tomorrow_holidays.iter().any(|h| h.chanukah_candle_count().is_some()) {
+            return Ok(None);
+        }
+        
+        let time = match date.weekday() {
+            Weekday::Fri => candle_lighting.cloned(),
+            Weekday::Sat => zmanim.tzeit_hakochavim.clone(),
+            _ => match (&zmanim.plag_hamincha, &zmanim.sunset) {
+                (Some(plag), Some(sunset)) => Some(format!("{}-{}", plag, sunset)),
+                _ => None,
+            },
+        };
+        Ok(time)
+    }
+    
+    /// Lazily calculate each day from `start` through `end` (inclusive)
+    ///
+    /// Days are computed only as the iterator is advanced, so callers can
+    /// stream, `take` or `filter` without building the whole range.
+    pub fn iter_days(
+        start: NaiveDate,
+        end: NaiveDate,
+        location: Option<GeoLocation>,
+        candle_offset_minutes: i64,
+    ) -> impl Iterator<Item = Result<DailyData, CalendarError>> {
+        start.iter_days()
+            .take_while(move |date| *date <= end)
+            .map(move |date| Self::calculate_day(date, CalendarSystem::Gregorian, location.clone(), candle_offset_minutes))
+    }
+    
+    /// List candle-lighting times for every Shabbat and Yom Tov in a Gregorian year
+    ///
+    /// Returns (date, time, label) for each evening candles are lit, e.g.
+    /// "Shabbat Bereshit" or "Erev Pesach". When the evening follows a Shabbat
+    /// or Yom Tov, candles are lit after nightfall (tzeit) rather than before
+    /// sunset, except on a Friday Yom Tov, when Shabbat candles still come
+    /// before sunset. `scheme` decides which festival days and parsha apply.
+    pub fn candle_lighting_calendar(
+        gregorian_year: i32,
+        location: &GeoLocation,
+        offset_minutes: i64,
+        scheme: HolidayScheme,
+    ) -> Result<Vec<(NaiveDate, String, String)>, CalendarError> {
+        let start = NaiveDate::from_ymd_opt(gregorian_year, 1, 1)
+            .ok_or_else(|| CalendarError::date_out_of_range(gregorian_year))?;
+        let end = NaiveDate::from_ymd_opt(gregorian_year, 12, 31)
+            .ok_or_else(|| CalendarError::date_out_of_range(gregorian_year))?;
+        Self::check_supported_range(start)?;
+        Self::check_supported_range(end)?;
+        
+        let calc = ZmanimCalculator::new(location.clone());
+        let mut entries = Vec::new();
+        
+        let mut holidays = HolidayCalculator::get_holidays_for_scheme(&DateConverter::gregorian_to_hebrew(start)?, scheme)?;
+        let mut current = start;
+        
+        while current <= end {
+            let next = current.succ_opt()
+                .ok_or_else(|| CalendarError::date_out_of_range(current))?;
+            let next_hebrew = DateConverter::gregorian_to_hebrew(next)?;
+            let next_holidays = HolidayCalculator::get_holidays_for_scheme(&next_hebrew, scheme)?;
+            
+            let yom_tov_tomorrow = next_holidays.iter().find(|h| h.is_yom_tov());
+            let shabbat_tomorrow = next.weekday() == Weekday::Sat;
+            
+            if yom_tov_tomorrow.is_some() || shabbat_tomorrow {
+                // After Shabbat or Yom Tov, candles are lit only after nightfall
+                let holy_today = current.weekday() == Weekday::Sat
+                    || holidays.iter().any(|h| h.is_yom_tov());
+                
+                let label = match yom_tov_tomorrow {
+                    Some(holiday) if holy_today => holiday.name().to_string(),
+                    Some(holiday) => {
+                        let base = holiday.name().split(" (").next().unwrap_or_default();
+                        format!("Erev {}", base)
+                    }
+                    None => Self::shabbat_label(ParshaCalculator::get_parsha_for_scheme(&next_hebrew, scheme)?),
+                };
+                
+                // Shabbat may not be desecrated, so a Friday Yom Tov still lights before sunset
+                let zmanim = calc.calculate(current)?;
+                let time = if holy_today && !shabbat_tomorrow {
+                    zmanim.tzeit_hakochavim.clone()
+                } else {
+                    calc.candle_lighting(&zmanim, offset_minutes)?
+                };
+                
+                if let Some(time) = time {
+                    entries.push((current, time, label));
+                }
+            }
+            
+            holidays = next_holidays;
+            current = next;
+        }
+        
+        Ok(entries)
+    }
+    
+    /// Candle lighting, parsha and havdalah for the Shabbat starting on `friday`
+    ///
+    /// Any other weekday is advanced to the upcoming Friday. `scheme` picks
+    /// the Israel or diaspora reading when the two differ.
+    pub fn shabbat_times(
+        friday: NaiveDate,
+        location: &GeoLocation,
+        candle_offset: i64,
+        havdalah_offset: i64,
+        scheme: HolidayScheme,
+    ) -> Result<ShabbatTimes, CalendarError> {
+        let to_friday = (4 - friday.weekday().num_days_from_monday() as i64).rem_euclid(7);
+        let friday = friday + chrono::Duration::days(to_friday);
+        let saturday = friday + chrono::Duration::days(1);
+        Self::check_supported_range(friday)?;
+        Self::check_supported_range(saturday)?;
+        
+        let calc = ZmanimCalculator::new(location.clone());
+        let candle_lighting = calc.candle_lighting(&calc.calculate(friday)?, candle_offset)?;
+        let havdalah = calc.havdalah(&calc.calculate(saturday)?, havdalah_offset)?;
+        let parsha = ParshaCalculator::get_parsha_for_scheme(&DateConverter::gregorian_to_hebrew(saturday)?, scheme)?;
+        
+        Ok(ShabbatTimes { friday, candle_lighting, parsha, havdalah })
+    }
+    
+    /// Candle lighting, parsha and havdalah for the Shabbat of `date`'s week
+    ///
+    /// Weeks run Sunday through Shabbat, so on Shabbat itself this is today's
+    /// Shabbat (candles were lit the evening before), unlike `shabbat_times`.
+    pub fn this_week_shabbat(
+        date: NaiveDate,
+        location: &GeoLocation,
+        candle_offset: i64,
+        havdalah_offset: i64,
+        scheme: HolidayScheme,
+    ) -> Result<ShabbatTimes, CalendarError> {
+        let to_friday = 5 - date.weekday().num_days_from_sunday() as i64;
+        let friday = date.checked_add_signed(chrono::Duration::days(to_friday))
+            .ok_or_else(|| CalendarError::date_out_of_range(date))?;
+        Self::shabbat_times(friday, location, candle_offset, havdalah_offset, scheme)
+    }
+    
+    /// Label a Shabbat by its reading, e.g. "Shabbat Bereshit"
+    fn shabbat_label(parsha: Parsha) -> String {
+        match parsha {
+            Parsha::HaftarahOnly => "Shabbat".to_string(),
+            Parsha::ShabbatCholHamoedSukkot | Parsha::ShabbatCholHamoedPesach => {
+                parsha.name().to_string()
+            }
+            _ => format!("Shabbat {}", parsha.name()),
+        }
+    }
+    
+    /// Which Adar a birthday (or bar/bat mitzvah) in Adar is observed in
+    ///
+    /// Someone born in Adar of a common year, or in Adar II, observes in Adar II
+    /// of a leap year; someone born in Adar I observes in Adar I. In a common
+    /// target year both fall in Adar. Other months are returned unchanged.
+    pub fn adar_observance(birth_month: HebrewMonth, target_year: i32) -> HebrewMonth {
+        match birth_month {
+            HebrewMonth::AdarI if DateConverter::is_hebrew_leap_year(target_year) => HebrewMonth::AdarI,
+            HebrewMonth::AdarI => HebrewMonth::Adar,
+            month => month,
+        }
+    }
+    
+    /// Bar or bat mitzvah date for a child born on a Gregorian date
+    ///
+    /// Returns (Hebrew date, Gregorian date, first Shabbat on or after it).
+    /// Adar births follow `adar_observance`. When the birth day does not exist
+    /// in the target month (30 Cheshvan or Kislev in a short year, 30 Adar I
+    /// in a common year) the date rolls over to the first of the next month.
+    /// The birth date is taken as the daytime Hebrew date; adjust for births
+    /// after sunset before calling.
+    pub fn bar_mitzvah(
+        birth: NaiveDate,
+        gender: Gender,
+    ) -> Result<(HebrewDate, NaiveDate, NaiveDate), CalendarError> {
+        let born = DateConverter::gregorian_to_hebrew(birth)?;
+        let age = match gender {
+            Gender::Male => 13,
+            Gender::Female => 12,
+        };
+        let year = born.year + age;
+        let month = Self::adar_observance(born.month, year);
+        
+        let first_of_month = HebrewDate::new(year, month, 1).gregorian()?;
+        let gregorian = first_of_month + chrono::Duration::days(born.day as i64 - 1);
+        let hebrew = DateConverter::gregorian_to_hebrew(gregorian)?;
+        
+        let days_to_shabbat = (6 - gregorian.weekday().num_days_from_sunday() as i64).rem_euclid(7);
+        let shabbat = gregorian + chrono::Duration::days(days_to_shabbat);
+        
+        Ok((hebrew, gregorian, shabbat))
+    }
+    
+    /// Completed Hebrew years between `birth` and `on`
+    ///
+    /// The birthday in each year follows `adar_observance`; a birth day missing
+    /// from the target month (e.g. 30 Kislev in a short year) is reached on the
+    /// first of the next month. Returns 0 if `on` precedes `birth`.
+    pub fn hebrew_age(birth: HebrewDate, on: HebrewDate) -> u32 {
+        // Position of a month counted from Tishrei
+        let month_index = |month: HebrewMonth, year: i32| {
+            let months = DateConverter::months_in_hebrew_year(year);
+            (month.to_number(months == 13) + months - 7) % months
+        };
+        
+        let birthday_month = Self::adar_observance(birth.month, on.year);
+        let birthday = (month_index(birthday_month, on.year), birth.day);
+        let today = (month_index(on.month, on.year), on.day);
+        
+        let years = on.year - birth.year - i32::from(today < birthday);
+        years.max(0) as u32
+    }
+    
+    /// Shabbat and holiday flags for every day of a Gregorian month (Diaspora)
+    ///
+    /// Only the first day is converted; the Hebrew date is then stepped
+    /// forward day by day.
+    pub fn month_flags(gregorian_year: i32, month: u32) -> Result<Vec<DayFlags>, CalendarError> {
+        let first = NaiveDate::from_ymd_opt(gregorian_year, month, 1).ok_or_else(|| {
+            CalendarError::InvalidDateFormat(format!("Invalid month {}-{:02}", gregorian_year, month))
+        })?;
+        Self::check_supported_range(first)?;
+        
+        let mut hebrew = DateConverter::gregorian_to_hebrew(first)?;
+        let mut flags = Vec::with_capacity(31);
+        for date in first.iter_days().take_while(|d| d.month() == month) {
+            let holidays = HolidayCalculator::get_holidays(&hebrew)?;
+            let next = DateConverter::next_hebrew_day(&hebrew)?;
+            // The 30th is the first day of a two-day Rosh Chodesh for the next month
+            let rosh_chodesh_month = match hebrew.day {
+                1 => Some(hebrew.month),
+                30 => Some(next.month),
+                _ => None,
+            };
+            flags.push(DayFlags {
+                date,
+                is_shabbat: date.weekday() == Weekday::Sat,
+                is_yom_tov: holidays.iter().any(Holiday::is_yom_tov),
+                is_rosh_chodesh: holidays.contains(&Holiday::RoshChodesh),
+                is_fast: holidays.iter().any(Holiday::is_fast_day),
+                is_chol_hamoed: holidays.iter().any(Holiday::is_chol_hamoed),
+                rosh_chodesh_month,
+            });
+            hebrew = next;
+        }
+        
+        Ok(flags)
+    }
+    
+    /// Validate a date range and return its inclusive day count
+    ///
+    /// The span `end - start` may be at most `max_days`; `end` must not precede `start`.
+    pub fn validate_range(start: NaiveDate, end: NaiveDate, max_days: i64) -> Result<u32, CalendarError> {
+        if end < start {
+            return Err(CalendarError::InvalidRange { start, end });
+        }
+        
+        let days = (end - start).num_days();
+        if days > max_days {
+            return Err(CalendarError::RangeTooLarge { requested: days, max: max_days });
+        }
+        
+        u32::try_from(days + 1)
+            .map_err(|_| CalendarError::RangeTooLarge { requested: days, max: max_days })
+    }
+    
+    /// Validate that a date is within the supported range (`MIN_DATE` to `MAX_DATE`)
+    pub(crate) fn check_supported_range(date: NaiveDate) -> Result<(), CalendarError> {
+        if date < Self::MIN_DATE || date > Self::MAX_DATE {
+            return Err(CalendarError::date_out_of_range(date));
+        }
+        
+        Ok(())
+    }
+    
+    /// Check whether an eruv tavshilin is needed on this date
+    ///
+    /// True on a Wednesday or Thursday when every day from tomorrow
+    /// through Friday is Yom Tov, so cooking for Shabbat happens on Yom Tov.
+    fn requires_eruv_tavshilin(date: NaiveDate) -> Result<bool, CalendarError> {
+        if !matches!(date.weekday(), Weekday::Wed | Weekday::Thu) {
+            return Ok(false);
+        }
+        
+        let is_yom_tov = |d: NaiveDate| -> Result<bool, CalendarError> {
+            let hebrew = DateConverter::gregorian_to_hebrew(d)?;
+            Ok(HolidayCalculator::get_holidays(&hebrew)?.iter().any(|h| h.is_yom_tov()))
+        };
+        
+        if is_yom_tov(date)? {
+            return Ok(false);
+        }
+        
+        let mut current = date;
+        while current.weekday() != Weekday::Fri {
+            current = current.succ_opt().ok_or_else(|| {
+                CalendarError::date_out_of_range(current)
+            })?;
+            if !is_yom_tov(current)? {
+                return Ok(false);
+            }
+        }
+        
+        Ok(true)
+    }
+    
+    /// Hebrew date at a local date and time, advancing after sunset
+    pub fn hebrew_date_at(datetime: NaiveDateTime, location: &GeoLocation) -> Result<HebrewDate, CalendarError> {
+        Self::hebrew_date_at_boundary(datetime, location, DayBoundary::Sunset)
+    }
+    
+    /// Hebrew date at a local date and time, advancing at the given boundary
+    ///
+    /// Where the boundary never occurs (polar day), the civil date is used.
+    /// The previous evening's boundary counts too, since at high latitudes
+    /// it can fall after midnight.
+    pub fn hebrew_date_at_boundary(
+        datetime: NaiveDateTime,
+        location: &GeoLocation,
+        boundary: DayBoundary,
+    ) -> Result<HebrewDate, CalendarError> {
+        let calc = ZmanimCalculator::new(location.clone());
+        // The moment the evening of `day` begins the next Hebrew day
+        let boundary_of = |day: NaiveDate| -> Result<Option<NaiveDateTime>, CalendarError> {
+            let times = calc.calculate_datetimes(day)?;
+            Ok(match boundary {
+                DayBoundary::Sunset => times.sunset,
+                DayBoundary::Tzeit => times.tzeit_hakochavim,
+            })
+        };
+        
+        let date = datetime.date();
+        let civil = if boundary_of(date)?.is_some_and(|start| datetime >= start) {
+            date.succ_opt().ok_or_else(|| CalendarError::date_out_of_range(date))?
+        } else {
+            match date.pred_opt() {
+                Some(yesterday) if boundary_of(yesterday)?.is_some_and(|start| datetime < start) => yesterday,
+                _ => date,
+            }
+        };
+        DateConverter::gregorian_to_hebrew(civil)
+    }
+    
+    /// Formats accepted by `parse_date` after ISO, in the order they are tried
+    const DATE_FORMATS: [(&'static str, &'static str); 3] = [
+        ("%m/%d/%Y", "MM/DD/YYYY"),
+        ("%d.%m.%Y", "DD.MM.YYYY"),
+        ("%B %d, %Y", "Month D, YYYY"),
+    ];
+    
+    /// Parse a date string (supports year 0 for 1 BCE)
+    ///
+    /// ISO `YYYY-MM-DD` is tried first, then `MM/DD/YYYY`, `DD.MM.YYYY`
+    /// and `Month D, YYYY`.
     pub fn parse_date(date_str: &str) -> Result<NaiveDate, CalendarError> {
+        let date_str = date_str.trim();
+        
         // Handle ISO-8601 extended years (e.g., +0000-01-01 or -0005-12-31)
-        let date = if date_str.starts_with('+') || date_str.starts_with('-') {
+        let iso = if date_str.starts_with('+') || date_str.starts_with('-') {
             chrono::NaiveDate::parse_from_str(date_str, "%Y-%m-%d")
-                .map_err(|e| CalendarError::InvalidDateFormat(e.to_string()))?
         } else {
             date_str.parse::<chrono::NaiveDate>()
-                .map_err(|e| CalendarError::InvalidDateFormat(e.to_string()))?
         };
+        if let Ok(date) = iso {
+            return Ok(date);
+        }
         
-        Ok(date)
+        Self::DATE_FORMATS.iter()
+            .find_map(|(format, _)| NaiveDate::parse_from_str(date_str, format).ok())
+            .ok_or_else(|| Self::unparseable(date_str))
     }
     
-    /// Format a date for display, handling year 0
-    pub fn format_display_date(date: NaiveDate) -> String {
-        let year = date.year();
-        let year_display = if year <= 0 {
-            format!("{} BCE", 1 - year)
-        } else {
-            format!("{} AD", year)
+    /// The error for a string matching none of the `parse_date` formats
+    fn unparseable(date_str: &str) -> CalendarError {
+        let accepted: Vec<&str> = Self::DATE_FORMATS.iter().map(|(_, name)| *name).collect();
+        CalendarError::InvalidDateFormat(format!(
+            "Could not parse '{}'; expected YYYY-MM-DD, {}",
+            date_str,
+            accepted.join(", ")
+        ))
+    }
+    
+    /// Parse a date string in the given calendar system
+    ///
+    /// Accepts the same formats as `parse_date`. The result is always the
+    /// proleptic Gregorian date used by `calculate_day`. Under `AutoByDate`,
+    /// dates before 1582-10-05 are read as Julian; the days 1582-10-05
+    /// through 1582-10-14 were skipped by the reform and are rejected.
+    pub fn parse_date_in(date_str: &str, system: CalendarSystem) -> Result<NaiveDate, CalendarError> {
+        match system {
+            CalendarSystem::Gregorian => Self::parse_date(date_str),
+            _ => Self::resolve_date(Self::split_date(date_str)?, system),
+        }
+    }
+    
+    /// The proleptic Gregorian date for a year, month and day written in `system`
+    fn resolve_date(ymd: (i32, u32, u32), system: CalendarSystem) -> Result<NaiveDate, CalendarError> {
+        let (year, month, day) = ymd;
+        let julian = match system {
+            CalendarSystem::Gregorian => false,
+            CalendarSystem::Julian => true,
+            CalendarSystem::AutoByDate => {
+                if ((1582, 10, 5)..=(1582, 10, 14)).contains(&ymd) {
+                    return Err(CalendarError::InvalidDateFormat(format!(
+                        "1582-10-{:02} falls in the days skipped by the 1582 Gregorian reform", day
+                    )));
+                }
+                ymd < (1582, 10, 5)
+            }
         };
         
+        if julian {
+            DateConverter::julian_to_gregorian(year, month, day)
+        } else {
+            NaiveDate::from_ymd_opt(year, month, day).ok_or_else(|| {
+                CalendarError::InvalidDateFormat(format!("{}-{:02}-{:02} is not a valid date", year, month, day))
+            })
+        }
+    }
+    
+    /// Split a date in any `parse_date` format into its numeric parts
+    ///
+    /// The parts are not checked against the Gregorian calendar, so Julian
+    /// leap days such as 1500-02-29 get through.
+    fn split_date(date_str: &str) -> Result<(i32, u32, u32), CalendarError> {
+        let date_str = date_str.trim();
+        core::iter::once("%Y-%m-%d")
+            .chain(Self::DATE_FORMATS.iter().map(|(format, _)| *format))
+            .find_map(|format| {
+                let mut parsed = Parsed::new();
+                chrono::format::parse(&mut parsed, date_str, StrftimeItems::new(format)).ok()?;
+                Some((parsed.year()?, parsed.month()?, parsed.day()?))
+            })
+            .ok_or_else(|| Self::unparseable(date_str))
+    }
+    
+    /// Format a date for display, handling year 0
+    pub fn format_display_date(date: NaiveDate) -> String {
+        Self::format_display_date_with(date, YearNumbering::Historical)
+    }
+    
+    /// Format a date for display with the given year numbering
+    pub fn format_display_date_with(date: NaiveDate, numbering: YearNumbering) -> String {
         format!("{} {}, {}", 
             date.month(),
             date.day(),
-            year_display
+            numbering.format_year(date.year())
         )
     }
 }
@@ -154,6 +805,34 @@ impl HebrewCalendar {
 mod tests {
     use super::*;
     
+    #[test]
+    fn test_calendar_error_codes() {
+        let cases = [
+            (CalendarError::date_out_of_range("2100-01-01"), "DATE_OUT_OF_RANGE", 400),
+            (CalendarError::InvalidDateFormat("x".into()), "INVALID_DATE_FORMAT", 400),
+            (CalendarError::InvalidLatitude(91.0), "INVALID_LATITUDE", 400),
+            (CalendarError::InvalidLongitude(181.0), "INVALID_LONGITUDE", 400),
+            (CalendarError::InvalidTimezone("Mars/Olympus".into()), "INVALID_TIMEZONE", 400),
+            (CalendarError::InvalidElevation(1e5), "INVALID_ELEVATION", 400),
+            (CalendarError::InvalidJson("x".into()), "INVALID_JSON", 400),
+            (CalendarError::PolarLocation(90.0), "POLAR_LOCATION", 400),
+            (CalendarError::RangeTooLarge { requested: 400, max: 366 }, "RANGE_TOO_LARGE", 400),
+            (
+                CalendarError::InvalidRange {
+                    start: NaiveDate::from_ymd_opt(2024, 2, 1).unwrap(),
+                    end: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+                },
+                "INVALID_RANGE",
+                400,
+            ),
+            (CalendarError::CalculationError("x".into()), "CALCULATION_ERROR", 500),
+        ];
+        for (err, code, status) in cases {
+            assert_eq!(err.code(), code);
+            assert_eq!(err.http_status(), status, "{}", code);
+        }
+    }
+    
     #[test]
     fn test_year_zero_boundary() {
         // Year 0 in ISO-8601 is 1 BCE
@@ -191,7 +870,7 @@ mod tests {
     fn test_calculate_day_pesach() {
         // April 23, 2024 = 15 Nisan 5784 = Pesach Day 1
         let date = NaiveDate::from_ymd_opt(2024, 4, 23).unwrap();
-        let data = HebrewCalendar::calculate_day(date, None, 18).unwrap();
+        let data = HebrewCalendar::calculate_day(date, CalendarSystem::Gregorian, None, 18).unwrap();
         assert_eq!(data.hebrew.month, calendar::HebrewMonth::Nisan);
         assert_eq!(data.hebrew.day, 15);
         assert!(data.holidays.contains(&holidays::Holiday::PesachDay1));
@@ -201,26 +880,285 @@ mod tests {
     fn test_calculate_day_with_location() {
         let date = NaiveDate::from_ymd_opt(2024, 6, 15).unwrap();
         let loc = zmanim::GeoLocation::jerusalem();
-        let data = HebrewCalendar::calculate_day(date, Some(loc), 18).unwrap();
+        let data = HebrewCalendar::calculate_day(date, CalendarSystem::Gregorian, Some(loc), 18).unwrap();
         assert!(data.zmanim.is_some(), "With location, zmanim should be present");
     }
 
     #[test]
     fn test_calculate_day_out_of_range() {
         let date = NaiveDate::from_ymd_opt(2051, 1, 1).unwrap();
-        let result = HebrewCalendar::calculate_day(date, None, 18);
+        let result = HebrewCalendar::calculate_day(date, CalendarSystem::Gregorian, None, 18);
         assert!(result.is_err());
         match result.unwrap_err() {
-            CalendarError::DateOutOfRange(_) => {},
+            CalendarError::DateOutOfRange { date, min, max } => {
+                assert_eq!(date, "2051-01-01");
+                assert_eq!(min, "0000-01-01");
+                assert_eq!(max, "2050-12-31");
+            }
             other => panic!("Expected DateOutOfRange, got {:?}", other),
         }
     }
 
+    #[test]
+    fn test_chanukah_lighting_friday() {
+        // Friday, December 8, 2023: second night of Chanukah 5784
+        let date = NaiveDate::from_ymd_opt(2023, 12, 8).unwrap();
+        let data = HebrewCalendar::calculate_day(date, CalendarSystem::Gregorian, Some(GeoLocation::jerusalem()), 40).unwrap();
+        assert!(data.candle_lighting.is_some());
+        assert_eq!(data.chanukah_lighting_time, data.candle_lighting);
+        
+        // Friday, December 15, 2023 is the eighth day; no lights that evening
+        let date = NaiveDate::from_ymd_opt(2023, 12, 15).unwrap();
+        let data = HebrewCalendar::calculate_day(date, CalendarSystem::Gregorian, Some(GeoLocation::jerusalem()), 40).unwrap();
+        assert_eq!(data.chanukah_lighting_time, None);
+    }
+    
+    #[test]
+    fn test_chanukah_lighting_motzei_shabbat() {
+        // Saturday, December 9, 2023: third night, lit after nightfall
+        let date = NaiveDate::from_ymd_opt(2023, 12, 9).unwrap();
+        let data = HebrewCalendar::calculate_day(date, CalendarSystem::Gregorian, Some(GeoLocation::jerusalem()), 40).unwrap();
+        let zmanim = data.zmanim.unwrap();
+        assert_eq!(data.chanukah_lighting_time, zmanim.tzeit_hakochavim);
+        
+        // Thursday, December 7, 2023: first night, plag-to-sunset window
+        let date = NaiveDate::from_ymd_opt(2023, 12, 7).unwrap();
+        let data = HebrewCalendar::calculate_day(date, CalendarSystem::Gregorian, Some(GeoLocation::jerusalem()), 40).unwrap();
+        let zmanim = data.zmanim.unwrap();
+        let expected = format!("{}-{}", zmanim.plag_hamincha.unwrap(), zmanim.sunset.unwrap());
+        assert_eq!(data.chanukah_lighting_time, Some(expected));
+    }
+    
+    #[test]
+    fn test_iter_days_is_lazy() {
+        // Days after MAX_DATE would fail, but take(5) never reaches them
+        let start = NaiveDate::from_ymd_opt(2050, 12, 27).unwrap();
+        let end = NaiveDate::from_ymd_opt(2051, 1, 31).unwrap();
+        let days: Vec<DailyData> = HebrewCalendar::iter_days(start, end, None, 18)
+            .take(5)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(days.len(), 5);
+        assert_eq!(days[4].gregorian.iso_string, "2050-12-31");
+        
+        let all = HebrewCalendar::iter_days(start, end, None, 18);
+        assert_eq!(all.filter(|day| day.is_err()).count(), 31);
+        
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        assert_eq!(HebrewCalendar::iter_days(start, start, None, 18).count(), 1);
+    }
+    
+    #[test]
+    fn test_adar_observance() {
+        // 5784 is a leap year, 5785 is not
+        assert_eq!(HebrewCalendar::adar_observance(HebrewMonth::Adar, 5784), HebrewMonth::Adar);
+        assert_eq!(HebrewCalendar::adar_observance(HebrewMonth::AdarI, 5784), HebrewMonth::AdarI);
+        assert_eq!(HebrewCalendar::adar_observance(HebrewMonth::AdarI, 5785), HebrewMonth::Adar);
+        assert_eq!(HebrewCalendar::adar_observance(HebrewMonth::Adar, 5785), HebrewMonth::Adar);
+        assert_eq!(HebrewCalendar::adar_observance(HebrewMonth::Shevat, 5784), HebrewMonth::Shevat);
+    }
+    
+    #[test]
+    fn test_bar_mitzvah_adar_born() {
+        // Born 14 Adar 5758 (common year); 5771 is a leap year → 14 Adar II
+        let birth = HebrewDate::new(5758, HebrewMonth::Adar, 14).gregorian().unwrap();
+        let (hebrew, gregorian, shabbat) = HebrewCalendar::bar_mitzvah(birth, Gender::Male).unwrap();
+        assert_eq!(hebrew, HebrewDate::new(5771, HebrewMonth::Adar, 14));
+        assert_eq!(gregorian, NaiveDate::from_ymd_opt(2011, 3, 20).unwrap());
+        assert_eq!(shabbat, NaiveDate::from_ymd_opt(2011, 3, 26).unwrap());
+        
+        // Bat mitzvah at 12: 5770 is common → plain Adar
+        let (hebrew, _, _) = HebrewCalendar::bar_mitzvah(birth, Gender::Female).unwrap();
+        assert_eq!(hebrew, HebrewDate::new(5770, HebrewMonth::Adar, 14));
+    }
+    
+    #[test]
+    fn test_bar_mitzvah_30_kislev_short_year() {
+        // Born 30 Kislev 5771; Kislev 5784 has only 29 days → 1 Teves
+        let birth = HebrewDate::new(5771, HebrewMonth::Kislev, 30).gregorian().unwrap();
+        let (hebrew, gregorian, shabbat) = HebrewCalendar::bar_mitzvah(birth, Gender::Male).unwrap();
+        assert_eq!(hebrew, HebrewDate::new(5784, HebrewMonth::Teves, 1));
+        assert_eq!(gregorian.weekday(), Weekday::Wed);
+        assert_eq!(shabbat.weekday(), Weekday::Sat);
+        assert_eq!((shabbat - gregorian).num_days(), 3);
+    }
+    
+    #[test]
+    fn test_hebrew_age_adar_born() {
+        // Born 25 Adar 5783 (common year); 5784 is leap, so the birthday is 25 Adar II
+        let birth = HebrewDate::new(5783, HebrewMonth::Adar, 25);
+        let age_on = |month, day| HebrewCalendar::hebrew_age(birth, HebrewDate::new(5784, month, day));
+        assert_eq!(age_on(HebrewMonth::AdarI, 25), 0);
+        assert_eq!(age_on(HebrewMonth::Adar, 24), 0);
+        assert_eq!(age_on(HebrewMonth::Adar, 25), 1);
+        assert_eq!(age_on(HebrewMonth::Nisan, 1), 1);
+        // Before the birthday in the new year, after Rosh Hashanah
+        assert_eq!(age_on(HebrewMonth::Tishrei, 1), 0);
+        // Adar I births observe in Adar I
+        let adar_i = HebrewDate::new(5784, HebrewMonth::AdarI, 25);
+        assert_eq!(HebrewCalendar::hebrew_age(adar_i, HebrewDate::new(5787, HebrewMonth::AdarI, 25)), 3);
+        assert_eq!(HebrewCalendar::hebrew_age(adar_i, HebrewDate::new(5787, HebrewMonth::AdarI, 24)), 2);
+        // Dates before birth
+        assert_eq!(HebrewCalendar::hebrew_age(birth, HebrewDate::new(5783, HebrewMonth::Adar, 1)), 0);
+    }
+    
+    #[test]
+    fn test_hebrew_age_missing_day() {
+        // Born 30 Kislev 5771; Kislev 5784 has 29 days, so 1 Teves completes the year
+        let birth = HebrewDate::new(5771, HebrewMonth::Kislev, 30);
+        assert_eq!(HebrewCalendar::hebrew_age(birth, HebrewDate::new(5784, HebrewMonth::Kislev, 29)), 12);
+        assert_eq!(HebrewCalendar::hebrew_age(birth, HebrewDate::new(5784, HebrewMonth::Teves, 1)), 13);
+    }
+    
+    #[test]
+    fn test_month_flags_april_2024() {
+        let flags = HebrewCalendar::month_flags(2024, 4).unwrap();
+        assert_eq!(flags.len(), 30);
+        // Diaspora Pesach 5784: Yom Tov April 23-24 and 29-30, Chol HaMoed 25-28
+        let yom_tov: Vec<u32> = flags.iter().filter(|f| f.is_yom_tov).map(|f| f.date.day()).collect();
+        assert_eq!(yom_tov, vec![23, 24, 29, 30]);
+        let chol_hamoed: Vec<u32> = flags.iter().filter(|f| f.is_chol_hamoed).map(|f| f.date.day()).collect();
+        assert_eq!(chol_hamoed, vec![25, 26, 27, 28]);
+        assert!(flags[26].is_shabbat);
+        
+        // Adar II has 29 days, so Rosh Chodesh Nisan is the single day April 9
+        let rosh_chodesh: Vec<u32> = flags.iter().filter(|f| f.is_rosh_chodesh).map(|f| f.date.day()).collect();
+        assert_eq!(rosh_chodesh, vec![9]);
+        assert!(flags.iter().all(|f| f.date.month() == 4 && !f.is_fast));
+        
+        assert!(HebrewCalendar::month_flags(2024, 13).is_err());
+    }
+    
+    #[test]
+    fn test_month_flags_two_day_rosh_chodesh() {
+        let rosh_chodesh = |flags: &[DayFlags]| -> Vec<(u32, Option<HebrewMonth>)> {
+            flags.iter()
+                .filter(|f| f.is_rosh_chodesh)
+                .map(|f| (f.date.day(), f.rosh_chodesh_month))
+                .collect()
+        };
+        
+        // 30 Adar I and 1 Adar II 5784 both fall in March 2024
+        let march = HebrewCalendar::month_flags(2024, 3).unwrap();
+        assert_eq!(rosh_chodesh(&march), vec![(10, Some(HebrewMonth::Adar)), (11, Some(HebrewMonth::Adar))]);
+        
+        // 30 Shevat 5785 ends February 2025 and 1 Adar begins March
+        let february = HebrewCalendar::month_flags(2025, 2).unwrap();
+        let march = HebrewCalendar::month_flags(2025, 3).unwrap();
+        assert_eq!(rosh_chodesh(&february).last(), Some(&(28, Some(HebrewMonth::Adar))));
+        assert_eq!(rosh_chodesh(&march).first(), Some(&(1, Some(HebrewMonth::Adar))));
+        assert!(march.iter().filter(|f| !f.is_rosh_chodesh).all(|f| f.rosh_chodesh_month.is_none()));
+    }
+    
+    #[test]
+    fn test_parse_date_in_calendar_system() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        
+        // The same written date, read as Julian vs proleptic Gregorian
+        let julian = HebrewCalendar::parse_date_in("1492-10-12", CalendarSystem::Julian).unwrap();
+        let gregorian = HebrewCalendar::parse_date_in("1492-10-12", CalendarSystem::Gregorian).unwrap();
+        assert_eq!(julian, ymd(1492, 10, 21));
+        assert_eq!(gregorian, ymd(1492, 10, 12));
+        assert_ne!(
+            HebrewCalendar::calculate_day(julian, CalendarSystem::Gregorian, None, 18).unwrap().hebrew,
+            HebrewCalendar::calculate_day(gregorian, CalendarSystem::Gregorian, None, 18).unwrap().hebrew
+        );
+        
+        // Auto switches at the reform and rejects the skipped days
+        let auto = |s| HebrewCalendar::parse_date_in(s, CalendarSystem::AutoByDate);
+        assert_eq!(auto("1582-10-04").unwrap(), ymd(1582, 10, 14));
+        assert_eq!(auto("1582-10-15").unwrap(), ymd(1582, 10, 15));
+        assert!(auto("1582-10-10").is_err());
+        assert_eq!(auto("1492-10-12").unwrap(), julian);
+        
+        // Every parse_date format works, trimmed, including Julian-only leap days
+        let julian_in = |s| HebrewCalendar::parse_date_in(s, CalendarSystem::Julian).unwrap();
+        assert_eq!(julian_in(" 1492-10-12 "), julian);
+        assert_eq!(julian_in("10/12/1492"), julian);
+        assert_eq!(julian_in("12.10.1492"), julian);
+        assert_eq!(julian_in("October 12, 1492"), julian);
+        assert_eq!(auto("10/12/1492").unwrap(), julian);
+        assert_eq!(julian_in("1500-02-29"), ymd(1500, 3, 10));
+        assert!(HebrewCalendar::parse_date_in("1501-02-29", CalendarSystem::Julian).is_err());
+        
+        // calculate_day reads its date in the requested system
+        let written = ymd(1492, 10, 12);
+        assert_eq!(
+            HebrewCalendar::calculate_day(written, CalendarSystem::Julian, None, 18).unwrap(),
+            HebrewCalendar::calculate_day(julian, CalendarSystem::Gregorian, None, 18).unwrap()
+        );
+        assert!(HebrewCalendar::calculate_day(ymd(1582, 10, 10), CalendarSystem::AutoByDate, None, 18).is_err());
+    }
+    
+    #[test]
+    fn test_daily_data_calendar_eq() {
+        let friday = NaiveDate::from_ymd_opt(2024, 6, 21).unwrap();
+        let new_york = HebrewCalendar::calculate_day(friday, CalendarSystem::Gregorian, Some(GeoLocation::new_york()), 18).unwrap();
+        let jerusalem = HebrewCalendar::calculate_day(friday, CalendarSystem::Gregorian, Some(GeoLocation::jerusalem()), 18).unwrap();
+        let nowhere = HebrewCalendar::calculate_day(friday, CalendarSystem::Gregorian, None, 18).unwrap();
+        
+        assert!(new_york.calendar_eq(&jerusalem));
+        assert!(new_york.calendar_eq(&nowhere));
+        assert_ne!(new_york, jerusalem);
+        
+        let saturday = HebrewCalendar::calculate_day(friday.succ_opt().unwrap(), CalendarSystem::Gregorian, Some(GeoLocation::new_york()), 18).unwrap();
+        assert!(!new_york.calendar_eq(&saturday));
+    }
+    
+    #[test]
+    fn test_daily_data_season() {
+        let date = NaiveDate::from_ymd_opt(2024, 7, 15).unwrap();
+        let data = HebrewCalendar::calculate_day(date, CalendarSystem::Gregorian, None, 18).unwrap();
+        assert_eq!(data.season, Some(Season::Tammuz));
+    }
+    
+    #[test]
+    fn test_early_shabbat_candle_lighting() {
+        let threshold = NaiveTime::from_hms_opt(19, 0, 0).unwrap();
+        let new_york = GeoLocation::new_york()
+            .with_timezone_id("America/New_York").unwrap()
+            .with_early_shabbat_threshold(threshold);
+        
+        // Friday, June 21, 2024: candle lighting is after 20:00
+        let friday = NaiveDate::from_ymd_opt(2024, 6, 21).unwrap();
+        let data = HebrewCalendar::calculate_day(friday, CalendarSystem::Gregorian, Some(new_york.clone()), 18).unwrap();
+        let early = data.early_shabbat_candle_lighting.expect("early Shabbat on a long Friday");
+        assert!(early < data.candle_lighting.unwrap());
+        assert_eq!(Some(early), data.zmanim.unwrap().plag_hamincha);
+        
+        // A winter Friday and a summer Thursday get no early option
+        let winter = NaiveDate::from_ymd_opt(2024, 12, 20).unwrap();
+        let data = HebrewCalendar::calculate_day(winter, CalendarSystem::Gregorian, Some(new_york.clone()), 18).unwrap();
+        assert_eq!(data.early_shabbat_candle_lighting, None);
+        let thursday = NaiveDate::from_ymd_opt(2024, 6, 20).unwrap();
+        let data = HebrewCalendar::calculate_day(thursday, CalendarSystem::Gregorian, Some(new_york), 18).unwrap();
+        assert_eq!(data.early_shabbat_candle_lighting, None);
+    }
+    
+    #[test]
+    fn test_calculate_day_unchecked_historical() {
+        // 68 CE, shortly before the destruction of the Second Temple
+        let date = NaiveDate::from_ymd_opt(68, 3, 15).unwrap();
+        let jerusalem = GeoLocation::jerusalem();
+        let data = HebrewCalendar::calculate_day_unchecked(date, Some(jerusalem), 40).unwrap();
+        assert_eq!(data.hebrew.year, 3828);
+        assert!(data.zmanim.is_some());
+        
+        // Before MIN_DATE: rejected by default, converted when unchecked
+        let bce = NaiveDate::from_ymd_opt(-70, 6, 1).unwrap();
+        assert!(HebrewCalendar::calculate_day(bce, CalendarSystem::Gregorian, None, 18).is_err());
+        assert_eq!(HebrewCalendar::calculate_day_unchecked(bce, None, 18).unwrap().hebrew.year, 3690);
+        
+        // Before the Hebrew epoch the conversion itself still fails
+        let pre_epoch = NaiveDate::from_ymd_opt(-3800, 1, 1).unwrap();
+        assert!(HebrewCalendar::calculate_day_unchecked(pre_epoch, None, 18).is_err());
+    }
+
     #[test]
     fn test_calculate_day_shabbat_yom_tov() {
         // Sept 16, 2023 = Shabbat, also Rosh Hashanah 5784
         let date = NaiveDate::from_ymd_opt(2023, 9, 16).unwrap();
-        let data = HebrewCalendar::calculate_day(date, None, 18).unwrap();
+        let data = HebrewCalendar::calculate_day(date, CalendarSystem::Gregorian, None, 18).unwrap();
         assert!(data.is_yom_tov, "Shabbat Rosh Hashanah should be yom tov");
     }
 
@@ -228,7 +1166,7 @@ mod tests {
     fn test_calculate_day_parsha_on_shabbat() {
         // Oct 14, 2023 = Shabbat = Tishrei 29, 5784 (Bereshit)
         let date = NaiveDate::from_ymd_opt(2023, 10, 14).unwrap();
-        let data = HebrewCalendar::calculate_day(date, None, 18).unwrap();
+        let data = HebrewCalendar::calculate_day(date, CalendarSystem::Gregorian, None, 18).unwrap();
         assert!(data.parsha.is_some(),
             "Shabbat should have parsha (bug fix validation)");
     }
@@ -237,11 +1175,35 @@ mod tests {
     fn test_calculate_day_parsha_not_on_weekday() {
         // Oct 10, 2023 = Tuesday
         let date = NaiveDate::from_ymd_opt(2023, 10, 10).unwrap();
-        let data = HebrewCalendar::calculate_day(date, None, 18).unwrap();
+        let data = HebrewCalendar::calculate_day(date, CalendarSystem::Gregorian, None, 18).unwrap();
         assert!(data.parsha.is_none(),
             "Tuesday should not have parsha (bug fix validation)");
     }
 
+    #[test]
+    fn test_eruv_tavshilin_before_yom_tov_into_shabbat() {
+        // Rosh Hashanah 5785 = Thu-Fri Oct 3-4, 2024
+        let wednesday = NaiveDate::from_ymd_opt(2024, 10, 2).unwrap();
+        let data = HebrewCalendar::calculate_day(wednesday, CalendarSystem::Gregorian, None, 18).unwrap();
+        assert!(data.requires_eruv_tavshilin, "Erev Rosh Hashanah before Thu-Fri needs eruv tavshilin");
+
+        let thursday = NaiveDate::from_ymd_opt(2024, 10, 3).unwrap();
+        let data = HebrewCalendar::calculate_day(thursday, CalendarSystem::Gregorian, None, 18).unwrap();
+        assert!(!data.requires_eruv_tavshilin, "Yom Tov itself is not the day to make the eruv");
+    }
+
+    #[test]
+    fn test_no_eruv_tavshilin_on_plain_weekday() {
+        // Shavuot 5784 = Wed-Thu June 12-13, 2024; Yom Tov ends before Friday
+        let tuesday = NaiveDate::from_ymd_opt(2024, 6, 11).unwrap();
+        let data = HebrewCalendar::calculate_day(tuesday, CalendarSystem::Gregorian, None, 18).unwrap();
+        assert!(!data.requires_eruv_tavshilin);
+
+        let wednesday = NaiveDate::from_ymd_opt(2024, 6, 5).unwrap();
+        let data = HebrewCalendar::calculate_day(wednesday, CalendarSystem::Gregorian, None, 18).unwrap();
+        assert!(!data.requires_eruv_tavshilin);
+    }
+
     #[test]
     fn test_format_display_date_ce() {
         let date = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
@@ -256,9 +1218,240 @@ mod tests {
         assert_eq!(display, "6 1, 1 BCE");
     }
 
+    #[test]
+    fn test_format_display_date_astronomical() {
+        let year_zero = NaiveDate::from_ymd_opt(0, 6, 1).unwrap();
+        let minus_five = NaiveDate::from_ymd_opt(-5, 6, 1).unwrap();
+        
+        assert_eq!(HebrewCalendar::format_display_date_with(year_zero, YearNumbering::Astronomical), "6 1, 0");
+        assert_eq!(HebrewCalendar::format_display_date_with(minus_five, YearNumbering::Astronomical), "6 1, -5");
+        assert_eq!(HebrewCalendar::format_display_date_with(minus_five, YearNumbering::Historical), "6 1, 6 BCE");
+        
+        assert_eq!(GregorianDate::from(year_zero).display, "6 1, 1 BCE");
+        assert_eq!(GregorianDate::from(year_zero).with_year_numbering(YearNumbering::Astronomical).display, "6 1, 0");
+        assert_eq!(GregorianDate::from(minus_five).display, "6 1, 6 BCE");
+        assert_eq!(GregorianDate::from(minus_five).with_year_numbering(YearNumbering::Astronomical).display, "6 1, -5");
+    }
+
     #[test]
     fn test_parse_date_invalid() {
         let result = HebrewCalendar::parse_date("not-a-date");
         assert!(result.is_err());
     }
+    
+    #[test]
+    fn test_parse_date_other_formats() {
+        let expected = NaiveDate::from_ymd_opt(2024, 4, 23).unwrap();
+        assert_eq!(HebrewCalendar::parse_date("04/23/2024").unwrap(), expected);
+        assert_eq!(HebrewCalendar::parse_date("23.04.2024").unwrap(), expected);
+        assert_eq!(HebrewCalendar::parse_date("April 23, 2024").unwrap(), expected);
+        assert_eq!(HebrewCalendar::parse_date("Apr 3, 2024").unwrap(), NaiveDate::from_ymd_opt(2024, 4, 3).unwrap());
+        
+        // Day-first with slashes is not accepted: there is no 23rd month
+        match HebrewCalendar::parse_date("23/04/2024") {
+            Err(CalendarError::InvalidDateFormat(msg)) => {
+                assert!(msg.contains("MM/DD/YYYY") && msg.contains("DD.MM.YYYY"), "{}", msg);
+            }
+            other => panic!("expected InvalidDateFormat, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn test_shabbat_times_new_york() {
+        let location = GeoLocation::new_york();
+        let friday = NaiveDate::from_ymd_opt(2024, 6, 21).unwrap();
+        let times = HebrewCalendar::shabbat_times(friday, &location, 18, 42, HolidayScheme::Diaspora).unwrap();
+        
+        assert_eq!(times.friday, friday);
+        assert_eq!(times.parsha, Parsha::Behaalotecha);
+        let day = HebrewCalendar::calculate_day(friday, CalendarSystem::Gregorian, Some(location.clone()), 18).unwrap();
+        assert_eq!(times.candle_lighting, day.candle_lighting);
+        let candles = times.candle_lighting.unwrap();
+        let havdalah = times.havdalah.unwrap();
+        assert!(havdalah > candles, "{} should follow {}", havdalah, candles);
+        
+        // Midweek dates advance to the coming Friday
+        let wednesday = NaiveDate::from_ymd_opt(2024, 6, 19).unwrap();
+        assert_eq!(HebrewCalendar::shabbat_times(wednesday, &location, 18, 42, HolidayScheme::Diaspora).unwrap().friday, friday);
+        let saturday = NaiveDate::from_ymd_opt(2024, 6, 22).unwrap();
+        assert_eq!(
+            HebrewCalendar::shabbat_times(saturday, &location, 18, 42, HolidayScheme::Diaspora).unwrap().friday,
+            NaiveDate::from_ymd_opt(2024, 6, 28).unwrap()
+        );
+    }
+    
+    #[test]
+    fn test_this_week_shabbat() {
+        let location = GeoLocation::new_york();
+        let friday = NaiveDate::from_ymd_opt(2024, 6, 21).unwrap();
+        let expected = HebrewCalendar::shabbat_times(friday, &location, 18, 42, HolidayScheme::Diaspora).unwrap();
+        
+        // Every day from Sunday through Shabbat gives the same Shabbat
+        let sunday = NaiveDate::from_ymd_opt(2024, 6, 16).unwrap();
+        for date in sunday.iter_days().take(7) {
+            assert_eq!(HebrewCalendar::this_week_shabbat(date, &location, 18, 42, HolidayScheme::Diaspora).unwrap(), expected, "{}", date);
+        }
+        
+        let monday = NaiveDate::from_ymd_opt(2024, 6, 17).unwrap();
+        let times = HebrewCalendar::this_week_shabbat(monday, &location, 18, 42, HolidayScheme::Diaspora).unwrap();
+        assert_eq!(times.friday, friday);
+        assert_eq!(times.parsha, Parsha::Behaalotecha);
+        assert!(times.candle_lighting.is_some() && times.havdalah.is_some());
+        
+        // On Shabbat itself it is still this week's Shabbat
+        let saturday = NaiveDate::from_ymd_opt(2024, 6, 22).unwrap();
+        assert_eq!(HebrewCalendar::this_week_shabbat(saturday, &location, 18, 42, HolidayScheme::Diaspora).unwrap().friday, friday);
+        
+        // The next Sunday starts a new week
+        let next_sunday = NaiveDate::from_ymd_opt(2024, 6, 23).unwrap();
+        assert_eq!(
+            HebrewCalendar::this_week_shabbat(next_sunday, &location, 18, 42, HolidayScheme::Diaspora).unwrap().parsha,
+            Parsha::Shelach
+        );
+        
+        // After Pesach 5782 Israel read a parsha ahead of the diaspora
+        let jerusalem = GeoLocation::jerusalem();
+        let friday = NaiveDate::from_ymd_opt(2022, 4, 29).unwrap();
+        let israel = HebrewCalendar::this_week_shabbat(friday, &jerusalem, 40, 42, HolidayScheme::Israel).unwrap();
+        let diaspora = HebrewCalendar::shabbat_times(friday, &jerusalem, 40, 42, HolidayScheme::Diaspora).unwrap();
+        assert_eq!(israel.parsha, Parsha::Kedoshim);
+        assert_eq!(diaspora.parsha, Parsha::AchreiMot);
+    }
+    
+    #[test]
+    fn test_candle_lighting_calendar_2024() {
+        let location = GeoLocation::jerusalem();
+        let entries = HebrewCalendar::candle_lighting_calendar(2024, &location, 40, HolidayScheme::Diaspora).unwrap();
+        
+        let fridays = entries.iter().filter(|(d, _, _)| d.weekday() == Weekday::Fri).count();
+        assert_eq!(fridays, 52);
+        
+        let erevs: Vec<_> = entries.iter().filter(|(_, _, l)| l.starts_with("Erev ")).collect();
+        assert!(erevs.iter().any(|(d, _, l)| {
+            *d == NaiveDate::from_ymd_opt(2024, 4, 22).unwrap() && l == "Erev Pesach"
+        }));
+        assert!(erevs.iter().any(|(d, _, l)| {
+            *d == NaiveDate::from_ymd_opt(2024, 10, 2).unwrap() && l == "Erev Rosh Hashanah"
+        }));
+        assert!(entries.iter().any(|(_, _, l)| l == "Shabbat Bereshit"));
+        
+        // Second night of Rosh Hashanah is lit after nightfall, not before sunset
+        let second_night = entries.iter()
+            .find(|(d, _, _)| *d == NaiveDate::from_ymd_opt(2024, 10, 3).unwrap())
+            .unwrap();
+        let first_night = erevs.iter()
+            .find(|(d, _, _)| *d == NaiveDate::from_ymd_opt(2024, 10, 2).unwrap())
+            .unwrap();
+        assert!(second_night.1 > first_night.1);
+        
+        // The second day of Rosh Hashanah 5785 was a Friday: Shabbat candles before sunset
+        let friday = NaiveDate::from_ymd_opt(2024, 10, 4).unwrap();
+        let (_, friday_time, _) = entries.iter().find(|(d, _, _)| *d == friday).unwrap();
+        let calc = ZmanimCalculator::new(location.clone());
+        let expected = calc.candle_lighting(&calc.calculate(friday).unwrap(), 40).unwrap().unwrap();
+        assert_eq!(*friday_time, expected);
+        assert!(*friday_time < second_night.1);
+    }
+    
+    #[test]
+    fn test_candle_lighting_calendar_israel() {
+        let location = GeoLocation::jerusalem();
+        let israel = HebrewCalendar::candle_lighting_calendar(2022, &location, 40, HolidayScheme::Israel).unwrap();
+        let diaspora = HebrewCalendar::candle_lighting_calendar(2022, &location, 40, HolidayScheme::Diaspora).unwrap();
+        let on = |entries: &[(NaiveDate, String, String)], m, d| {
+            let date = NaiveDate::from_ymd_opt(2022, m, d).unwrap();
+            entries.iter().find(|(day, _, _)| *day == date).map(|(_, _, label)| label.clone())
+        };
+        
+        // No second-night candles for the first day of Pesach in Israel
+        assert!(on(&diaspora, 4, 16).is_some());
+        assert_eq!(on(&israel, 4, 16), None);
+        
+        // The 8th day of Pesach 5782 was a Shabbat, so Israel read ahead
+        assert_eq!(on(&israel, 4, 29).unwrap(), HebrewCalendar::shabbat_label(Parsha::Kedoshim));
+        assert_eq!(on(&diaspora, 4, 29).unwrap(), HebrewCalendar::shabbat_label(Parsha::AchreiMot));
+    }
+
+    #[test]
+    fn test_validate_range_boundary() {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let at_max = start + chrono::Duration::days(366);
+        assert_eq!(HebrewCalendar::validate_range(start, at_max, 366), Ok(367));
+        assert_eq!(HebrewCalendar::validate_range(start, start, 366), Ok(1));
+        
+        let over = at_max.succ_opt().unwrap();
+        assert_eq!(
+            HebrewCalendar::validate_range(start, over, 366),
+            Err(CalendarError::RangeTooLarge { requested: 367, max: 366 })
+        );
+        assert_eq!(
+            HebrewCalendar::validate_range(over, start, 366),
+            Err(CalendarError::InvalidRange { start: over, end: start })
+        );
+    }
+
+    #[cfg(feature = "binary")]
+    #[test]
+    fn test_daily_data_binary_roundtrip() {
+        // Friday of Chol HaMoed Pesach 5784: exercises holidays, parsha and nested zmanim
+        let date = NaiveDate::from_ymd_opt(2024, 4, 26).unwrap();
+        let data = HebrewCalendar::calculate_day(date, CalendarSystem::Gregorian, Some(GeoLocation::jerusalem()), 40).unwrap();
+        
+        let bytes = data.to_bytes().unwrap();
+        assert!(bytes.len() < serde_json::to_vec(&data).unwrap().len());
+        assert_eq!(DailyData::from_bytes(&bytes).unwrap(), data);
+        
+        assert!(DailyData::from_bytes(&bytes[..bytes.len() / 2]).is_err());
+    }
+
+    #[test]
+    fn test_hebrew_date_at_after_sunset() {
+        // Sunset in Jerusalem on September 15, 2023 is around 18:00
+        let jerusalem = GeoLocation::jerusalem();
+        let date = NaiveDate::from_ymd_opt(2023, 9, 15).unwrap();
+        
+        let afternoon = HebrewCalendar::hebrew_date_at(date.and_hms_opt(14, 0, 0).unwrap(), &jerusalem).unwrap();
+        let evening = HebrewCalendar::hebrew_date_at(date.and_hms_opt(21, 0, 0).unwrap(), &jerusalem).unwrap();
+        assert_eq!(afternoon, HebrewDate::new(5783, calendar::HebrewMonth::Elul, 29));
+        assert_eq!(evening, HebrewDate::new(5784, calendar::HebrewMonth::Tishrei, 1));
+    }
+    
+    #[test]
+    fn test_hebrew_date_at_tzeit_boundary() {
+        // Between sunset and nightfall the day has changed only under the sunset boundary
+        let jerusalem = GeoLocation::jerusalem();
+        let date = NaiveDate::from_ymd_opt(2023, 9, 15).unwrap();
+        let zmanim = ZmanimCalculator::new(jerusalem.clone()).calculate(date).unwrap();
+        let dusk = date.and_time(NaiveTime::parse_from_str(zmanim.sunset.as_ref().unwrap(), "%H:%M").unwrap())
+            + chrono::Duration::minutes(5);
+        
+        let by_sunset = HebrewCalendar::hebrew_date_at_boundary(dusk, &jerusalem, DayBoundary::Sunset).unwrap();
+        let by_tzeit = HebrewCalendar::hebrew_date_at_boundary(dusk, &jerusalem, DayBoundary::Tzeit).unwrap();
+        assert_eq!(by_sunset.day, 1);
+        assert_eq!(by_tzeit.day, 29);
+    }
+    
+    #[test]
+    fn test_hebrew_date_at_boundary_after_midnight() {
+        // At 59°N in June, nightfall after June 1, 2024 (24 Iyar) is 00:42 on June 2
+        let north = GeoLocation::new(59.0, 24.9).unwrap().with_timezone(180).unwrap();
+        let june_2 = NaiveDate::from_ymd_opt(2024, 6, 2).unwrap();
+        let at = |h, m| {
+            HebrewCalendar::hebrew_date_at_boundary(june_2.and_hms_opt(h, m, 0).unwrap(), &north, DayBoundary::Tzeit)
+                .unwrap()
+        };
+        assert_eq!(at(0, 30), HebrewDate::new(5784, calendar::HebrewMonth::Iyar, 24));
+        assert_eq!(at(1, 0), HebrewDate::new(5784, calendar::HebrewMonth::Iyar, 25));
+        assert_eq!(at(12, 0), HebrewDate::new(5784, calendar::HebrewMonth::Iyar, 25));
+        
+        // At 60°N nightfall after May 24, 2024 (16 Iyar) is 00:29 on May 25
+        let north = GeoLocation::new(60.0, 24.9).unwrap().with_timezone(180).unwrap();
+        let may_25 = NaiveDate::from_ymd_opt(2024, 5, 25).unwrap();
+        let at = |h, m| {
+            HebrewCalendar::hebrew_date_at_boundary(may_25.and_hms_opt(h, m, 0).unwrap(), &north, DayBoundary::Tzeit)
+                .unwrap()
+        };
+        assert_eq!(at(0, 15), HebrewDate::new(5784, calendar::HebrewMonth::Iyar, 16));
+        assert_eq!(at(0, 30), HebrewDate::new(5784, calendar::HebrewMonth::Iyar, 17));
+    }
 }
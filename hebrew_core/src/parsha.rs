@@ -2,11 +2,15 @@
 //! 
 //! Implements the calculation of weekly Torah portions based on Hebrew calendar rules.
 
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 
 use crate::calendar::{DateConverter, HebrewDate, HebrewMonth};
+use crate::holidays::{Holiday, HolidayCalculator, HolidayScheme};
 use crate::CalendarError;
-use chrono::Datelike;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
 
 /// Torah portion (Parsha)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -73,6 +77,8 @@ pub enum Parsha {
     ChukatBalak,      // Combined (in Israel)
     MatotMasei,       // Combined
     NitzavimVayeilech, // Combined
+    ShabbatCholHamoedSukkot, // Festival reading replaces the parsha
+    ShabbatCholHamoedPesach, // Festival reading replaces the parsha
     HaftarahOnly,     // When no regular parsha
 }
 
@@ -141,6 +147,8 @@ impl Parsha {
             Parsha::ChukatBalak => "Chukat-Balak",
             Parsha::MatotMasei => "Matot-Masei",
             Parsha::NitzavimVayeilech => "Nitzavim-Vayeilech",
+            Parsha::ShabbatCholHamoedSukkot => "Shabbat Chol HaMoed Sukkot",
+            Parsha::ShabbatCholHamoedPesach => "Shabbat Chol HaMoed Pesach",
             Parsha::HaftarahOnly => "Haftarah Only",
         }
     }
@@ -202,28 +210,300 @@ impl Parsha {
             Parsha::Vayeilech => "וילך",
             Parsha::HaAzinu => "האזינו",
             Parsha::VezotHaberacha => "וזאת הברכה",
+            Parsha::ShabbatCholHamoedSukkot => "שבת חול המועד סוכות",
+            Parsha::ShabbatCholHamoedPesach => "שבת חול המועד פסח",
             _ => "",
         }
     }
+    
+    /// The individual parshiot making up this reading
+    ///
+    /// Combined readings return both members; any other reading returns itself.
+    pub fn components(&self) -> Vec<Parsha> {
+        match self {
+            Parsha::VayakhelPekudei => vec![Parsha::Vayakhel, Parsha::Pekudei],
+            Parsha::TazriaMetzora => vec![Parsha::Tazria, Parsha::Metzora],
+            Parsha::AchreiMotKedoshim => vec![Parsha::AchreiMot, Parsha::Kedoshim],
+            Parsha::BeharBechukotai => vec![Parsha::Behar, Parsha::Bechukotai],
+            Parsha::ChukatBalak => vec![Parsha::Chukat, Parsha::Balak],
+            Parsha::MatotMasei => vec![Parsha::Matot, Parsha::Masei],
+            Parsha::NitzavimVayeilech => vec![Parsha::Nitzavim, Parsha::Vayeilech],
+            _ => vec![*self],
+        }
+    }
+    
+    /// The reading after this one in the cycle that begins with Bereshit of `year`
+    ///
+    /// Follows that year's combinations: after Matot comes Masei when they
+    /// are read apart, and Devarim when they are combined (a member of a
+    /// combined reading steps from the combination). Vezot Haberacha is
+    /// followed by Bereshit.
+    pub fn next_in_cycle(&self, year: i32, scheme: HolidayScheme) -> Result<Parsha, CalendarError> {
+        let cycle = ParshaCalculator::reading_cycle(year, scheme)?;
+        let position = self.positions_in(&cycle)?.1;
+        Ok(cycle.get(position + 1).copied().unwrap_or(Parsha::Bereshit))
+    }
+    
+    /// The reading before this one in the cycle that begins with Bereshit of `year`
+    ///
+    /// Bereshit is preceded by Vezot Haberacha.
+    pub fn prev_in_cycle(&self, year: i32, scheme: HolidayScheme) -> Result<Parsha, CalendarError> {
+        let cycle = ParshaCalculator::reading_cycle(year, scheme)?;
+        let position = self.positions_in(&cycle)?.0;
+        Ok(position.checked_sub(1).map_or(Parsha::VezotHaberacha, |i| cycle[i]))
+    }
+    
+    /// First and last entries of `cycle` sharing a parsha with this reading
+    fn positions_in(&self, cycle: &[Parsha]) -> Result<(usize, usize), CalendarError> {
+        let parts = self.components();
+        let overlaps = |reading: &Parsha| reading.components().iter().any(|p| parts.contains(p));
+        match (cycle.iter().position(overlaps), cycle.iter().rposition(overlaps)) {
+            (Some(first), Some(last)) => Ok((first, last)),
+            _ => Err(CalendarError::CalculationError(
+                format!("{} is not part of the weekly reading cycle", self.name())
+            )),
+        }
+    }
+}
+
+/// A Shabbat named for its special reading
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SpecialShabbat {
+    Shekalim,
+    Zachor,
+    Parah,
+    HaChodesh,
+    HaGadol,
+    /// Between Rosh Hashanah and Yom Kippur
+    Shuva,
+    /// Parashat Beshalach, with the Song at the Sea
+    Shirah,
+    /// Before Tisha B'Av (or on it, when the fast is postponed)
+    Chazon,
+    /// After Tisha B'Av
+    Nachamu,
+}
+
+impl SpecialShabbat {
+    /// Get the English name
+    pub fn name(&self) -> &'static str {
+        match self {
+            SpecialShabbat::Shekalim => "Shabbat Shekalim",
+            SpecialShabbat::Zachor => "Shabbat Zachor",
+            SpecialShabbat::Parah => "Shabbat Parah",
+            SpecialShabbat::HaChodesh => "Shabbat HaChodesh",
+            SpecialShabbat::HaGadol => "Shabbat HaGadol",
+            SpecialShabbat::Shuva => "Shabbat Shuva",
+            SpecialShabbat::Shirah => "Shabbat Shirah",
+            SpecialShabbat::Chazon => "Shabbat Chazon",
+            SpecialShabbat::Nachamu => "Shabbat Nachamu",
+        }
+    }
+}
+
+/// Everything notable about a Shabbat, for a Shabbat header
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShabbatContext {
+    /// The Shabbat itself
+    pub date: HebrewDate,
+    pub special: Option<SpecialShabbat>,
+    /// The coming month is blessed (not before Tishrei)
+    pub is_mevarchim: bool,
+    /// Rosh Chodesh falls on the following Sunday
+    pub is_machar_chodesh: bool,
+    pub is_rosh_chodesh: bool,
+    /// A Yom Tov or Chol HaMoed day that coincides with this Shabbat
+    pub festival: Option<Holiday>,
+}
+
+/// A weekly reading in the triennial cycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TriennialReading {
+    /// The annual-cycle parsha for this Shabbat
+    pub parsha: Parsha,
+    /// Year of the three-year cycle (1, 2 or 3); year N reads the Nth third of the aliyot
+    pub cycle_year: u8,
+}
+
+impl TriennialReading {
+    /// Human-readable portion of the parsha read this year
+    pub fn portion_name(&self) -> &'static str {
+        match self.cycle_year {
+            1 => "First third",
+            2 => "Second third",
+            _ => "Final third",
+        }
+    }
 }
 
 /// Parsha calculator
 pub struct ParshaCalculator;
 
 impl ParshaCalculator {
-    /// Get the parsha for a Shabbat
+    /// Get the parsha for a Shabbat (diaspora schedule)
     pub fn get_parsha(date: &HebrewDate) -> Result<Parsha, CalendarError> {
+        Self::get_parsha_for_scheme(date, HolidayScheme::Diaspora)
+    }
+    
+    /// Get the parsha for a Shabbat under a festival scheme
+    ///
+    /// Israel and the diaspora diverge after an eighth day of Pesach or second
+    /// day of Shavuot on Shabbat, until the diaspora catches up by combining.
+    pub fn get_parsha_for_scheme(date: &HebrewDate, scheme: HolidayScheme) -> Result<Parsha, CalendarError> {
         // Find the Shabbat of this date
         let shabbat_date = Self::find_shabbat(date)?;
         
         // Calculate based on the Hebrew year cycle
-        Self::calculate_parsha_for_shabbat(shabbat_date)
+        Self::calculate_parsha_for_shabbat(shabbat_date, scheme)
+    }
+    
+    /// The Israel and diaspora parsha for a Shabbat, as (israel, diaspora)
+    pub fn get_parsha_both(date: &HebrewDate) -> Result<(Parsha, Parsha), CalendarError> {
+        let shabbat = Self::find_shabbat(date)?;
+        Ok((
+            Self::calculate_parsha_for_shabbat(shabbat, HolidayScheme::Israel)?,
+            Self::calculate_parsha_for_shabbat(shabbat, HolidayScheme::Diaspora)?,
+        ))
+    }
+    
+    /// The Shabbat on which a reading falls in a Hebrew year
+    ///
+    /// Returns None when the parsha is combined with its neighbour that year
+    /// (ask for the combined reading instead) or is not read on a Shabbat.
+    pub fn date_of(parsha: Parsha, year: i32, scheme: HolidayScheme) -> Result<Option<HebrewDate>, CalendarError> {
+        Self::year_schedule(year, scheme)?
+            .into_iter()
+            .find(|(_, reading)| *reading == parsha)
+            .map(|(shabbat, _)| DateConverter::gregorian_to_hebrew(shabbat))
+            .transpose()
+    }
+    
+    /// Get the special Shabbat (Arba Parshiyot or HaGadol) for the Shabbat of this date
+    pub fn special_shabbat(date: &HebrewDate) -> Result<Option<SpecialShabbat>, CalendarError> {
+        let shabbat = Self::find_shabbat(date)?;
+        let day = shabbat.gregorian()?;
+        let on = |month, d| HebrewDate::new(shabbat.year, month, d).gregorian();
+        let within = |from: chrono::NaiveDate, to: chrono::NaiveDate| day >= from && day <= to;
+        
+        // Adar here is the Adar of Purim (Adar II in leap years)
+        let rosh_chodesh_adar = on(HebrewMonth::Adar, 1)?;
+        let rosh_chodesh_nisan = on(HebrewMonth::Nisan, 1)?;
+        let week = chrono::Duration::days(6);
+        // Parah is the Shabbat before HaChodesh
+        let parah_end = rosh_chodesh_nisan - chrono::Duration::days(7);
+        
+        let special = if within(rosh_chodesh_adar - week, rosh_chodesh_adar) {
+            Some(SpecialShabbat::Shekalim)
+        } else if within(on(HebrewMonth::Adar, 7)?, on(HebrewMonth::Adar, 13)?) {
+            Some(SpecialShabbat::Zachor)
+        } else if within(rosh_chodesh_nisan - week, rosh_chodesh_nisan) {
+            Some(SpecialShabbat::HaChodesh)
+        } else if within(parah_end - week, parah_end) {
+            Some(SpecialShabbat::Parah)
+        } else if within(on(HebrewMonth::Nisan, 8)?, on(HebrewMonth::Nisan, 14)?) {
+            Some(SpecialShabbat::HaGadol)
+        } else if within(on(HebrewMonth::Tishrei, 3)?, on(HebrewMonth::Tishrei, 9)?) {
+            Some(SpecialShabbat::Shuva)
+        } else if within(on(HebrewMonth::Av, 3)?, on(HebrewMonth::Av, 9)?) {
+            Some(SpecialShabbat::Chazon)
+        } else if within(on(HebrewMonth::Av, 10)?, on(HebrewMonth::Av, 16)?) {
+            Some(SpecialShabbat::Nachamu)
+        } else if shabbat.month == HebrewMonth::Shevat
+            && Self::calculate_parsha_for_shabbat(shabbat, HolidayScheme::Diaspora)? == Parsha::Beshalach
+        {
+            Some(SpecialShabbat::Shirah)
+        } else {
+            None
+        };
+        
+        Ok(special)
+    }
+    
+    /// Describe the Shabbat of this date
+    pub fn shabbat_context(date: &HebrewDate) -> Result<ShabbatContext, CalendarError> {
+        let shabbat = Self::find_shabbat(date)?;
+        let holidays = HolidayCalculator::get_holidays(&shabbat)?;
+        
+        // The last Shabbat of a month precedes Rosh Chodesh; on the 29th,
+        // Rosh Chodesh begins the next day
+        let before_new_month = shabbat.month != HebrewMonth::Elul;
+        
+        Ok(ShabbatContext {
+            date: shabbat,
+            special: Self::special_shabbat(&shabbat)?,
+            is_mevarchim: before_new_month && (23..=29).contains(&shabbat.day),
+            is_machar_chodesh: before_new_month && shabbat.day == 29,
+            is_rosh_chodesh: holidays.contains(&Holiday::RoshChodesh),
+            festival: holidays.into_iter().find(|h| h.is_yom_tov() || h.is_chol_hamoed()),
+        })
+    }
+    
+    /// Get the triennial-cycle reading for a Shabbat
+    ///
+    /// `cycle_start_year` is the Hebrew year whose Bereshit begins year 1 of the cycle.
+    pub fn triennial_reading(date: &HebrewDate, cycle_start_year: i32) -> Result<TriennialReading, CalendarError> {
+        let shabbat_date = Self::find_shabbat(date)?;
+        let parsha = Self::calculate_parsha_for_shabbat(shabbat_date, HolidayScheme::Diaspora)?;
+        let cycle_year = (shabbat_date.year - cycle_start_year).rem_euclid(3) as u8 + 1;
+        
+        Ok(TriennialReading { parsha, cycle_year })
+    }
+    
+    /// Pirkei Avot chapter(s) studied on a Shabbat afternoon between Pesach and Rosh Hashanah
+    ///
+    /// Chapters cycle 1–6 from the first Shabbat after Pesach; a Shabbat that is
+    /// Shavuot or Tisha B'Av is skipped. In the final partial cycle chapters are
+    /// doubled from the end, so 5–6 are read together on the last Shabbat before
+    /// Rosh Hashanah (then 3–4, then 1–2, as needed).
+    ///
+    /// Israel can differ in the doubling weeks: when 22 Nisan is Shabbat it is
+    /// already an ordinary Shabbat there and the cycle starts a week earlier, and
+    /// a Shabbat on 7 Sivan is second-day Shavuot only in the diaspora.
+    /// Returns None for weekdays and Shabbatot outside the season.
+    pub fn pirkei_avot_chapter(date: &HebrewDate, scheme: HolidayScheme) -> Result<Option<Vec<u8>>, CalendarError> {
+        let day = date.gregorian()?;
+        if day.weekday() != chrono::Weekday::Sat {
+            return Ok(None);
+        }
+        
+        let on = |month, d| HebrewDate::new(date.year, month, d).gregorian();
+        let days = scheme.festival_days();
+        let last_day_of_pesach = on(HebrewMonth::Nisan, if days.pesach { 22 } else { 21 })?;
+        let rosh_hashanah = HebrewDate::new(date.year + 1, HebrewMonth::Tishrei, 1).gregorian()?;
+        let mut skipped = vec![on(HebrewMonth::Sivan, 6)?, on(HebrewMonth::Av, 9)?];
+        if days.shavuot {
+            skipped.push(on(HebrewMonth::Sivan, 7)?);
+        }
+        
+        let to_shabbat = (5 - last_day_of_pesach.weekday().num_days_from_sunday() as i64).rem_euclid(7) + 1;
+        let first = last_day_of_pesach + chrono::Duration::days(to_shabbat);
+        let shabbatot: Vec<chrono::NaiveDate> = (0..)
+            .map(|week| first + chrono::Duration::weeks(week))
+            .take_while(|shabbat| *shabbat < rosh_hashanah)
+            .filter(|shabbat| !skipped.contains(shabbat))
+            .collect();
+        let Some(index) = shabbatot.iter().position(|shabbat| *shabbat == day) else {
+            return Ok(None);
+        };
+        
+        // Whole cycles read one chapter a week
+        let remaining = shabbatot.len() % 6;
+        let full_cycles = shabbatot.len() - remaining;
+        if index < full_cycles {
+            return Ok(Some(vec![(index % 6) as u8 + 1]));
+        }
+        
+        // Spread the six chapters over the remaining weeks, larger groups last
+        let week = index - full_cycles;
+        let size = |w: usize| 6 / remaining + usize::from(w >= remaining - 6 % remaining);
+        let start: usize = (0..week).map(size).sum();
+        Ok(Some((start + 1..=start + size(week)).map(|c| c as u8).collect()))
     }
     
     /// Find the Shabbat containing this date
     fn find_shabbat(date: &HebrewDate) -> Result<HebrewDate, CalendarError> {
         // Convert to Gregorian to find day of week
-        let gregorian = DateConverter::hebrew_to_gregorian(*date)?;
+        let gregorian = date.gregorian()?;
         let weekday = gregorian.weekday().num_days_from_sunday();
         
         // Shabbat is day 6 (0-indexed from Sunday = 0)
@@ -238,120 +518,163 @@ impl ParshaCalculator {
     }
     
     /// Calculate the parsha for a Shabbat
-    fn calculate_parsha_for_shabbat(date: HebrewDate) -> Result<Parsha, CalendarError> {
-        let year = date.year;
-        let is_leap = DateConverter::is_hebrew_leap_year(year);
-
-        // Find Rosh Hashanah of this year
-        let rosh_hashanah = HebrewDate::new(year, HebrewMonth::Tishrei, 1);
-        let rosh_gregorian = DateConverter::hebrew_to_gregorian(rosh_hashanah)?;
-        let rh_weekday = rosh_gregorian.weekday().num_days_from_sunday();
-
-        // Find Simchat Torah (Tishrei 23 in diaspora)
-        let simchat_torah = HebrewDate::new(year, HebrewMonth::Tishrei, 23);
-        let simchat_gregorian = DateConverter::hebrew_to_gregorian(simchat_torah)?;
-        let st_weekday = simchat_gregorian.weekday().num_days_from_sunday();
-
-        // Find the first Shabbat after Simchat Torah (Shabbat Bereshit)
-        let days_to_shabbat = if st_weekday == 6 {
-            7 // If Simchat Torah is Shabbat, Bereshit is next week
-        } else {
-            (6 - st_weekday as i64 + 7) % 7
-        };
-        let bereshit_shabbat = simchat_gregorian + chrono::Duration::days(days_to_shabbat);
-
-        // Count weeks from Shabbat Bereshit to current Shabbat
-        let current_gregorian = DateConverter::hebrew_to_gregorian(date)?;
-        let weeks_diff = (current_gregorian - bereshit_shabbat).num_days() / 7;
-
-        if weeks_diff < 0 {
-            // Before Bereshit (during Tishrei holidays)
-            return Ok(Parsha::HaftarahOnly);
-        }
-
-        // Get the base parsha index based on year type
-        let parsha_index = Self::get_parsha_index(weeks_diff as usize, is_leap, rh_weekday, year);
-
-        Ok(parsha_index)
+    fn calculate_parsha_for_shabbat(date: HebrewDate, scheme: HolidayScheme) -> Result<Parsha, CalendarError> {
+        let day = date.gregorian()?;
+        Self::year_schedule(date.year, scheme)?
+            .into_iter()
+            .find(|(shabbat, _)| *shabbat == day)
+            .map(|(_, reading)| reading)
+            .ok_or_else(|| CalendarError::CalculationError(format!("{} is not a Shabbat", day)))
     }
     
-    /// Get the parsha index based on week number and year type
-    fn get_parsha_index(week: usize, is_leap: bool, rh_weekday: u32, year: i32) -> Parsha {
-        // Standard sequence of parshiot
-        let standard_sequence: Vec<Parsha> = vec![
-            Parsha::Bereshit, Parsha::Noach, Parsha::LechLecha, Parsha::Vayera,
-            Parsha::ChayeiSara, Parsha::Toldot, Parsha::Vayetzei, Parsha::Vayishlach,
-            Parsha::Vayeshev, Parsha::Miketz, Parsha::Vayigash, Parsha::Vayechi,
-            Parsha::Shemot, Parsha::Vaera, Parsha::Bo, Parsha::Beshalach,
-            Parsha::Yitro, Parsha::Mishpatim, Parsha::Terumah, Parsha::Tetzaveh,
-            Parsha::KiTisa, Parsha::Vayakhel, Parsha::Pekudei, Parsha::Vayikra,
-            Parsha::Tzav, Parsha::Shemini, Parsha::Tazria, Parsha::Metzora,
-            Parsha::AchreiMot, Parsha::Kedoshim, Parsha::Emor, Parsha::Behar,
-            Parsha::Bechukotai, Parsha::Bamidbar, Parsha::Nasso, Parsha::Behaalotecha,
-            Parsha::Shelach, Parsha::Korach, Parsha::Chukat, Parsha::Balak,
-            Parsha::Pinchas, Parsha::Matot, Parsha::Masei, Parsha::Devarim,
-            Parsha::Vaetchanan, Parsha::Eikev, Parsha::Reeh, Parsha::Shoftim,
-            Parsha::KiTeitzei, Parsha::KiTavo, Parsha::Nitzavim, Parsha::Vayeilech,
-            Parsha::HaAzinu,
-        ];
-        
-        // For leap years or special configurations, parshiot are combined
-        // This is a simplified version - full implementation would handle all edge cases
-        
-        let adjusted_week = Self::adjust_for_combined_parshiot(week, is_leap, rh_weekday, year);
+    /// The Torah readings in order from Bereshit of `year` through Vezot Haberacha
+    fn reading_cycle(year: i32, scheme: HolidayScheme) -> Result<Vec<Parsha>, CalendarError> {
+        let is_torah_reading = |reading: &Parsha| !matches!(reading,
+            Parsha::HaftarahOnly | Parsha::ShabbatCholHamoedSukkot | Parsha::ShabbatCholHamoedPesach
+        );
+        let readings = |year| -> Result<Vec<Parsha>, CalendarError> {
+            Ok(Self::year_schedule(year, scheme)?
+                .into_iter()
+                .map(|(_, reading)| reading)
+                .filter(is_torah_reading)
+                .collect())
+        };
         
-        if adjusted_week < standard_sequence.len() {
-            standard_sequence[adjusted_week]
-        } else if adjusted_week == standard_sequence.len() {
-            Parsha::VezotHaberacha
-        } else {
-            Parsha::HaftarahOnly
-        }
+        let mut cycle: Vec<Parsha> = readings(year)?
+            .into_iter()
+            .skip_while(|reading| *reading != Parsha::Bereshit)
+            .collect();
+        // Haazinu (and perhaps Vayeilech) fall in the next Tishrei, before Bereshit
+        cycle.extend(readings(year + 1)?.into_iter().take_while(|reading| *reading != Parsha::Bereshit));
+        cycle.push(Parsha::VezotHaberacha);
+        Ok(cycle)
     }
     
-    /// Adjust week number for combined parshiot
-    fn adjust_for_combined_parshiot(week: usize, is_leap: bool, rh_weekday: u32, year: i32) -> usize {
-        // In leap years, fewer parshiot are combined
-        // In common years starting on certain days, more combinations occur
+    /// Every Shabbat of a Hebrew year with its reading
+    ///
+    /// Shabbatot on Yom Tov read `HaftarahOnly` and those on Chol HaMoed the
+    /// festival reading. The rest are filled in order, season by season, so
+    /// that Tzav (Metzora in a leap year) precedes Pesach, Bamidbar precedes
+    /// Shavuot, Devarim precedes Tisha B'Av and Nitzavim precedes Rosh
+    /// Hashanah; a season with too few Shabbatot combines pairs in its
+    /// customary order, and one with too many reads ahead.
+    fn year_schedule(year: i32, scheme: HolidayScheme) -> Result<Vec<(NaiveDate, Parsha)>, CalendarError> {
+        let on = |month, day| HebrewDate::new(year, month, day).gregorian();
+        let next_rosh_hashanah = HebrewDate::new(year + 1, HebrewMonth::Tishrei, 1).gregorian()?;
+        let is_leap = DateConverter::is_hebrew_leap_year(year);
+        let index_of = |parsha| PARSHIOT.iter().position(|p| *p == parsha).unwrap_or(0);
+        
+        // Vayeilech is read before Rosh Hashanah only when no Shabbat Shuva precedes Haazinu
+        let vayeilech_before_rosh_hashanah = matches!(next_rosh_hashanah.weekday(), Weekday::Thu | Weekday::Sat);
+        let seasons = [
+            (on(HebrewMonth::Nisan, 15)?, if is_leap { Parsha::Metzora } else { Parsha::Tzav },
+             &[Parsha::VayakhelPekudei][..]),
+            (on(HebrewMonth::Sivan, 6)?, Parsha::Bamidbar,
+             &[Parsha::TazriaMetzora, Parsha::AchreiMotKedoshim, Parsha::BeharBechukotai][..]),
+            (on(HebrewMonth::Av, 10)?, Parsha::Devarim,
+             &[Parsha::MatotMasei, Parsha::ChukatBalak][..]),
+            (next_rosh_hashanah, if vayeilech_before_rosh_hashanah { Parsha::Vayeilech } else { Parsha::Nitzavim },
+             &[Parsha::NitzavimVayeilech][..]),
+        ];
         
-        // Special handling based on year configuration
-        let _year_type = DateConverter::hebrew_year_type(year);
+        let rosh_hashanah = on(HebrewMonth::Tishrei, 1)?;
+        let sukkot = on(HebrewMonth::Tishrei, 15)?;
+        let first_shabbat = rosh_hashanah + Duration::days((6 - rosh_hashanah.weekday().num_days_from_sunday() as i64).rem_euclid(7));
         
-        // Simplified combination rules:
-        // In Israel, Chukat and Balak are often combined in common years
-        // In diaspora, they are usually separate
+        let mut schedule = Vec::new();
+        let mut regular = Vec::new();
+        for shabbat in first_shabbat.iter_weeks().take_while(|d| *d < next_rosh_hashanah) {
+            let hebrew = DateConverter::gregorian_to_hebrew(shabbat)?;
+            let holidays = HolidayCalculator::get_holidays_for_scheme(&hebrew, scheme)?;
+            if holidays.iter().any(Holiday::is_yom_tov) {
+                schedule.push((shabbat, Parsha::HaftarahOnly));
+            } else if holidays.iter().any(Holiday::is_chol_hamoed) {
+                let reading = if hebrew.month == HebrewMonth::Nisan {
+                    Parsha::ShabbatCholHamoedPesach
+                } else {
+                    Parsha::ShabbatCholHamoedSukkot
+                };
+                schedule.push((shabbat, reading));
+            } else if shabbat < sukkot {
+                schedule.push((shabbat, Parsha::HaAzinu));
+            } else {
+                regular.push(shabbat);
+            }
+        }
         
-        // This is a basic implementation - a full implementation would have
-        // detailed tables for all year configurations
+        // Before Sukkot: Vayeilech on Shabbat Shuva when Haazinu has a later Shabbat
+        let early: Vec<usize> = schedule.iter().enumerate()
+            .filter(|(_, (_, reading))| *reading == Parsha::HaAzinu)
+            .map(|(i, _)| i)
+            .collect();
+        if early.len() == 2 {
+            schedule[early[0]].1 = Parsha::Vayeilech;
+        }
         
-        match (is_leap, rh_weekday, week) {
-            // Vayakhel-Pekudei combination
-            (_, _, 21) if !is_leap && week > 20 => week - 1,
-            
-            // Tazria-Metzora combination
-            (_, _, 26) if !is_leap && week > 25 => week - 1,
-            
-            // Achrei Mot-Kedoshim combination
-            (_, _, 29) if !is_leap && week > 28 => week - 1,
+        let mut next = 0;
+        let mut remaining = regular.as_slice();
+        for (season_end, anchor, combinations) in seasons {
+            let slots = remaining.iter().take_while(|d| **d < season_end).count();
+            let (season, rest) = remaining.split_at(slots);
+            remaining = rest;
             
-            // Behar-Bechukotai combination
-            (_, _, 32) if !is_leap && week > 31 => week - 1,
+            // Read through the anchor, or further ahead if Shabbatot are left over
+            let through = (index_of(anchor) + 1).max(next + slots).min(PARSHIOT.len());
+            let planned = &PARSHIOT[next..through];
+            next = through;
             
-            // Matot-Masei combination
-            (_, _, 41) if !is_leap && week > 40 => week - 1,
+            let excess = planned.len().saturating_sub(slots);
+            let combined: Vec<Parsha> = combinations.iter()
+                .copied()
+                .filter(|pair| pair.components().iter().all(|p| planned.contains(p)))
+                .take(excess)
+                .collect();
+            if combined.len() < excess {
+                return Err(CalendarError::CalculationError(
+                    format!("Cannot fit the readings of {} before {}", year, season_end)
+                ));
+            }
             
-            // Nitzavim-Vayeilech combination
-            (false, _, 50) => 49, // Combined
-            
-            _ => week,
+            let mut readings = Vec::with_capacity(slots);
+            let mut parshiot = planned.iter().copied().peekable();
+            while let Some(parsha) = parshiot.next() {
+                match combined.iter().find(|pair| pair.components()[0] == parsha) {
+                    Some(pair) => {
+                        parshiot.next();
+                        readings.push(*pair);
+                    }
+                    None => readings.push(parsha),
+                }
+            }
+            schedule.extend(season.iter().copied().zip(readings));
         }
+        
+        schedule.sort_by_key(|(shabbat, _)| *shabbat);
+        Ok(schedule)
     }
 }
 
+/// The annual cycle from Bereshit, read in order (Haazinu and Vezot
+/// Haberacha fall in Tishrei and are handled separately)
+const PARSHIOT: [Parsha; 52] = [
+    Parsha::Bereshit, Parsha::Noach, Parsha::LechLecha, Parsha::Vayera,
+    Parsha::ChayeiSara, Parsha::Toldot, Parsha::Vayetzei, Parsha::Vayishlach,
+    Parsha::Vayeshev, Parsha::Miketz, Parsha::Vayigash, Parsha::Vayechi,
+    Parsha::Shemot, Parsha::Vaera, Parsha::Bo, Parsha::Beshalach,
+    Parsha::Yitro, Parsha::Mishpatim, Parsha::Terumah, Parsha::Tetzaveh,
+    Parsha::KiTisa, Parsha::Vayakhel, Parsha::Pekudei, Parsha::Vayikra,
+    Parsha::Tzav, Parsha::Shemini, Parsha::Tazria, Parsha::Metzora,
+    Parsha::AchreiMot, Parsha::Kedoshim, Parsha::Emor, Parsha::Behar,
+    Parsha::Bechukotai, Parsha::Bamidbar, Parsha::Nasso, Parsha::Behaalotecha,
+    Parsha::Shelach, Parsha::Korach, Parsha::Chukat, Parsha::Balak,
+    Parsha::Pinchas, Parsha::Matot, Parsha::Masei, Parsha::Devarim,
+    Parsha::Vaetchanan, Parsha::Eikev, Parsha::Reeh, Parsha::Shoftim,
+    Parsha::KiTeitzei, Parsha::KiTavo, Parsha::Nitzavim, Parsha::Vayeilech,
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::NaiveDate;
     
     #[test]
     fn test_parsha_calculation() {
@@ -381,6 +704,13 @@ mod tests {
         assert_eq!(Parsha::Bereshit.hebrew_name(), "בראשית");
     }
 
+    #[test]
+    fn test_parsha_components() {
+        assert_eq!(Parsha::VayakhelPekudei.components(), vec![Parsha::Vayakhel, Parsha::Pekudei]);
+        assert_eq!(Parsha::NitzavimVayeilech.components(), vec![Parsha::Nitzavim, Parsha::Vayeilech]);
+        assert_eq!(Parsha::Bereshit.components(), vec![Parsha::Bereshit]);
+    }
+
     #[test]
     fn test_shabbat_bereishit_5784() {
         // Tishrei 28, 5784 = Oct 13, 2023 (Shabbat) = Parashat Bereshit
@@ -430,7 +760,7 @@ mod tests {
         while current < end {
             let hebrew = DateConverter::gregorian_to_hebrew(current).unwrap();
             let _parsha = ParshaCalculator::get_parsha(&hebrew).unwrap();
-            current = current + chrono::Duration::days(7);
+            current += chrono::Duration::days(7);
         }
     }
 
@@ -448,4 +778,224 @@ mod tests {
         let hebrew = DateConverter::gregorian_to_hebrew(current).unwrap();
         let _parsha = ParshaCalculator::get_parsha(&hebrew).unwrap();
     }
+
+    #[test]
+    fn test_shabbat_chol_hamoed_sukkot() {
+        // Tishrei 17, 5785 = Saturday Oct 19, 2024
+        let date = HebrewDate::new(5785, HebrewMonth::Tishrei, 17);
+        let parsha = ParshaCalculator::get_parsha(&date).unwrap();
+        assert_eq!(parsha, Parsha::ShabbatCholHamoedSukkot);
+    }
+
+    #[test]
+    fn test_shabbat_chol_hamoed_pesach() {
+        // Nisan 19, 5784 = Saturday April 27, 2024
+        let date = HebrewDate::new(5784, HebrewMonth::Nisan, 19);
+        let parsha = ParshaCalculator::get_parsha(&date).unwrap();
+        assert_eq!(parsha, Parsha::ShabbatCholHamoedPesach);
+        assert_eq!(parsha.name(), "Shabbat Chol HaMoed Pesach");
+    }
+
+    #[test]
+    fn test_triennial_bereshit_thirds() {
+        // Shabbat Bereshit of 5784, 5785 and 5786 reads successive thirds
+        let bereshit = [
+            (chrono::NaiveDate::from_ymd_opt(2023, 10, 14).unwrap(), 1),
+            (chrono::NaiveDate::from_ymd_opt(2024, 10, 26).unwrap(), 2),
+            (chrono::NaiveDate::from_ymd_opt(2025, 10, 18).unwrap(), 3),
+        ];
+        
+        for (gregorian, expected) in bereshit {
+            let date = DateConverter::gregorian_to_hebrew(gregorian).unwrap();
+            let reading = ParshaCalculator::triennial_reading(&date, 5784).unwrap();
+            assert_eq!(reading.parsha, Parsha::Bereshit, "{}", gregorian);
+            assert_eq!(reading.cycle_year, expected, "{}", gregorian);
+        }
+        
+        // The cycle wraps back to the first third
+        let date = HebrewDate::new(5787, HebrewMonth::Cheshvan, 1);
+        assert_eq!(ParshaCalculator::triennial_reading(&date, 5784).unwrap().cycle_year, 1);
+    }
+
+    #[test]
+    fn test_shabbat_context_machar_chodesh() {
+        // Saturday, November 30, 2024 = 29 Cheshvan 5785; Rosh Chodesh Kislev is Sunday
+        let date = HebrewDate::new(5785, HebrewMonth::Cheshvan, 29);
+        let context = ParshaCalculator::shabbat_context(&date).unwrap();
+        assert_eq!(context.date, date);
+        assert!(context.is_machar_chodesh);
+        assert!(context.is_mevarchim);
+        assert!(!context.is_rosh_chodesh);
+        assert_eq!(context.festival, None);
+        
+        // The Shabbat of Chol HaMoed Sukkot is a festival Shabbat
+        let sukkot = ParshaCalculator::shabbat_context(&HebrewDate::new(5785, HebrewMonth::Tishrei, 17)).unwrap();
+        assert!(!sukkot.is_machar_chodesh);
+        assert_eq!(sukkot.festival, Some(Holiday::SukkotCholHamoedDay1));
+    }
+    
+    #[test]
+    fn test_pirkei_avot_5784() {
+        let chapter = |y, m, d, scheme| {
+            let date = DateConverter::gregorian_to_hebrew(chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap()).unwrap();
+            ParshaCalculator::pirkei_avot_chapter(&date, scheme).unwrap()
+        };
+        
+        // First Shabbat after Pesach (26 Nisan) starts the cycle
+        assert_eq!(chapter(2024, 5, 4, HolidayScheme::Diaspora), Some(vec![1]));
+        assert_eq!(chapter(2024, 5, 11, HolidayScheme::Diaspora), Some(vec![2]));
+        // 22 Shabbatot: three full cycles, then 1, 2, 3–4, 5–6
+        assert_eq!(chapter(2024, 9, 7, HolidayScheme::Diaspora), Some(vec![1]));
+        assert_eq!(chapter(2024, 9, 21, HolidayScheme::Diaspora), Some(vec![3, 4]));
+        assert_eq!(chapter(2024, 9, 28, HolidayScheme::Diaspora), Some(vec![5, 6]));
+        
+        // Weekdays and winter Shabbatot
+        assert_eq!(chapter(2024, 5, 5, HolidayScheme::Diaspora), None);
+        assert_eq!(chapter(2024, 4, 27, HolidayScheme::Diaspora), None);
+        assert_eq!(chapter(2024, 12, 7, HolidayScheme::Diaspora), None);
+    }
+    
+    #[test]
+    fn test_pirkei_avot_israel_starts_earlier() {
+        // 5778: 22 Nisan (April 7, 2018) is Shabbat, the eighth day of Pesach outside Israel
+        let date = DateConverter::gregorian_to_hebrew(chrono::NaiveDate::from_ymd_opt(2018, 4, 7).unwrap()).unwrap();
+        assert_eq!(ParshaCalculator::pirkei_avot_chapter(&date, HolidayScheme::Israel).unwrap(), Some(vec![1]));
+        assert_eq!(ParshaCalculator::pirkei_avot_chapter(&date, HolidayScheme::Diaspora).unwrap(), None);
+        
+        // Both end with 5–6 on the last Shabbat before Rosh Hashanah 5779
+        let last = DateConverter::gregorian_to_hebrew(chrono::NaiveDate::from_ymd_opt(2018, 9, 8).unwrap()).unwrap();
+        for scheme in [HolidayScheme::Israel, HolidayScheme::Diaspora] {
+            assert_eq!(ParshaCalculator::pirkei_avot_chapter(&last, scheme).unwrap(), Some(vec![5, 6]));
+        }
+    }
+    
+    #[test]
+    fn test_date_of_matot() {
+        let gregorian = |parsha, year, scheme| {
+            ParshaCalculator::date_of(parsha, year, scheme).unwrap().map(|d| d.gregorian().unwrap())
+        };
+        let ymd = |y, m, d| chrono::NaiveDate::from_ymd_opt(y, m, d);
+        
+        // 5784: Matot and Masei are read together on August 3, 2024
+        assert_eq!(gregorian(Parsha::Matot, 5784, HolidayScheme::Diaspora), None);
+        assert_eq!(gregorian(Parsha::MatotMasei, 5784, HolidayScheme::Diaspora), ymd(2024, 8, 3));
+        // 5774: Achrei Mot falls before Pesach and Matot is read alone
+        assert_eq!(gregorian(Parsha::Matot, 5774, HolidayScheme::Diaspora), ymd(2014, 7, 19));
+        assert_eq!(gregorian(Parsha::Masei, 5774, HolidayScheme::Diaspora), ymd(2014, 7, 26));
+        
+        assert_eq!(gregorian(Parsha::Bereshit, 5785, HolidayScheme::Diaspora), ymd(2024, 10, 26));
+        assert_eq!(gregorian(Parsha::VezotHaberacha, 5785, HolidayScheme::Diaspora), None);
+    }
+    
+    #[test]
+    fn test_next_in_cycle_across_combinations() {
+        use HolidayScheme::Diaspora;
+        
+        // 5784 (leap): Matot and Masei combined, Tazria and Metzora apart
+        assert_eq!(Parsha::Pinchas.next_in_cycle(5784, Diaspora).unwrap(), Parsha::MatotMasei);
+        assert_eq!(Parsha::MatotMasei.next_in_cycle(5784, Diaspora).unwrap(), Parsha::Devarim);
+        assert_eq!(Parsha::Matot.next_in_cycle(5784, Diaspora).unwrap(), Parsha::Devarim);
+        assert_eq!(Parsha::Devarim.prev_in_cycle(5784, Diaspora).unwrap(), Parsha::MatotMasei);
+        assert_eq!(Parsha::Tazria.next_in_cycle(5784, Diaspora).unwrap(), Parsha::Metzora);
+        
+        // 5774 (leap): Matot and Masei read apart
+        assert_eq!(Parsha::Matot.next_in_cycle(5774, Diaspora).unwrap(), Parsha::Masei);
+        assert_eq!(Parsha::Devarim.prev_in_cycle(5774, Diaspora).unwrap(), Parsha::Masei);
+        assert_eq!(Parsha::MatotMasei.next_in_cycle(5774, Diaspora).unwrap(), Parsha::Devarim);
+        
+        // 5785 (common): Tazria-Metzora and Achrei Mot-Kedoshim combined
+        assert_eq!(Parsha::Shemini.next_in_cycle(5785, Diaspora).unwrap(), Parsha::TazriaMetzora);
+        assert_eq!(Parsha::TazriaMetzora.next_in_cycle(5785, Diaspora).unwrap(), Parsha::AchreiMotKedoshim);
+        assert_eq!(Parsha::Metzora.prev_in_cycle(5785, Diaspora).unwrap(), Parsha::Shemini);
+        
+        // The cycle closes with Haazinu and Vezot Haberacha
+        assert_eq!(Parsha::NitzavimVayeilech.next_in_cycle(5784, Diaspora).unwrap(), Parsha::HaAzinu);
+        assert_eq!(Parsha::HaAzinu.next_in_cycle(5784, Diaspora).unwrap(), Parsha::VezotHaberacha);
+        assert_eq!(Parsha::VezotHaberacha.next_in_cycle(5784, Diaspora).unwrap(), Parsha::Bereshit);
+        assert_eq!(Parsha::Bereshit.prev_in_cycle(5784, Diaspora).unwrap(), Parsha::VezotHaberacha);
+        assert!(Parsha::HaftarahOnly.next_in_cycle(5784, Diaspora).is_err());
+    }
+    
+    #[test]
+    fn test_parsha_israel_divergence_5782() {
+        // 22 Nisan 5782 was Shabbat: Israel reads ahead until splitting Matot and Masei
+        let on = |y, m, d| DateConverter::gregorian_to_hebrew(chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap()).unwrap();
+        let both = |date| {
+            (ParshaCalculator::get_parsha_for_scheme(&date, HolidayScheme::Israel).unwrap(),
+             ParshaCalculator::get_parsha_for_scheme(&date, HolidayScheme::Diaspora).unwrap())
+        };
+        
+        assert_eq!(both(on(2022, 4, 23)), (Parsha::AchreiMot, Parsha::HaftarahOnly));
+        assert_eq!(both(on(2022, 6, 4)), (Parsha::Nasso, Parsha::Bamidbar));
+        assert_eq!(both(on(2022, 7, 23)), (Parsha::Matot, Parsha::Pinchas));
+        assert_eq!(both(on(2022, 7, 30)), (Parsha::Masei, Parsha::MatotMasei));
+        assert_eq!(both(on(2022, 8, 6)), (Parsha::Devarim, Parsha::Devarim));
+    }
+    
+    #[test]
+    fn test_get_parsha_both() {
+        let on = |y, m, d| DateConverter::gregorian_to_hebrew(chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap()).unwrap();
+        
+        // Israel is a week ahead after 22 Nisan 5782 fell on Shabbat
+        let (israel, diaspora) = ParshaCalculator::get_parsha_both(&on(2022, 5, 5)).unwrap();
+        assert_eq!((israel, diaspora), (Parsha::Emor, Parsha::Kedoshim));
+        
+        assert_eq!(ParshaCalculator::get_parsha_both(&on(2024, 1, 27)).unwrap(), (Parsha::Beshalach, Parsha::Beshalach));
+    }
+    
+    #[test]
+    fn test_parsha_common_year_combinations_5785() {
+        let on = |m, d| DateConverter::gregorian_to_hebrew(chrono::NaiveDate::from_ymd_opt(2025, m, d).unwrap()).unwrap();
+        let parsha = |m, d| ParshaCalculator::get_parsha(&on(m, d)).unwrap();
+        
+        // A complete common year starting Thursday reads Vayakhel and Pekudei apart
+        assert_eq!(parsha(3, 22), Parsha::Vayakhel);
+        assert_eq!(parsha(4, 12), Parsha::Tzav);
+        assert_eq!(parsha(5, 3), Parsha::TazriaMetzora);
+        assert_eq!(parsha(5, 24), Parsha::BeharBechukotai);
+        assert_eq!(parsha(7, 26), Parsha::MatotMasei);
+        // Rosh Hashanah 5786 is Tuesday: Nitzavim alone, Vayeilech on Shabbat Shuva
+        assert_eq!(parsha(9, 20), Parsha::Nitzavim);
+        assert_eq!(parsha(9, 27), Parsha::Vayeilech);
+        assert_eq!(parsha(10, 4), Parsha::HaAzinu);
+    }
+    
+    #[test]
+    fn test_special_shabbatot_5784() {
+        // 5784 is a leap year: the Arba Parshiyot fall around Adar II
+        let cases = [
+            ((2024, 3, 9), SpecialShabbat::Shekalim),
+            ((2024, 3, 23), SpecialShabbat::Zachor),
+            ((2024, 3, 30), SpecialShabbat::Parah),
+            ((2024, 4, 6), SpecialShabbat::HaChodesh),
+            ((2024, 4, 20), SpecialShabbat::HaGadol),
+        ];
+        for ((y, m, d), expected) in cases {
+            let date = DateConverter::gregorian_to_hebrew(chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap()).unwrap();
+            assert_eq!(ParshaCalculator::special_shabbat(&date).unwrap(), Some(expected), "{}-{}-{}", y, m, d);
+        }
+        
+        let plain = DateConverter::gregorian_to_hebrew(chrono::NaiveDate::from_ymd_opt(2024, 3, 16).unwrap()).unwrap();
+        assert_eq!(ParshaCalculator::special_shabbat(&plain).unwrap(), None);
+    }
+    
+    #[test]
+    fn test_special_shabbatot_by_reading_5784() {
+        let cases = [
+            ((2023, 9, 23), SpecialShabbat::Shuva),
+            ((2024, 1, 27), SpecialShabbat::Shirah),
+            ((2024, 8, 10), SpecialShabbat::Chazon),
+            ((2024, 8, 17), SpecialShabbat::Nachamu),
+        ];
+        for ((y, m, d), expected) in cases {
+            let date = DateConverter::gregorian_to_hebrew(chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap()).unwrap();
+            assert_eq!(ParshaCalculator::special_shabbat(&date).unwrap(), Some(expected), "{}-{}-{}", y, m, d);
+        }
+        
+        // Beshalach week: any day resolves to the coming Shabbat Shirah
+        let tuesday = DateConverter::gregorian_to_hebrew(chrono::NaiveDate::from_ymd_opt(2024, 1, 23).unwrap()).unwrap();
+        assert_eq!(ParshaCalculator::special_shabbat(&tuesday).unwrap(), Some(SpecialShabbat::Shirah));
+        let yitro = DateConverter::gregorian_to_hebrew(chrono::NaiveDate::from_ymd_opt(2024, 2, 3).unwrap()).unwrap();
+        assert_eq!(ParshaCalculator::special_shabbat(&yitro).unwrap(), None);
+    }
 }
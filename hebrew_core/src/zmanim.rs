@@ -3,47 +3,201 @@
 //! Implements astronomical calculations for sunrise, sunset, and other halachic times.
 //! Uses NOAA algorithms for solar position calculations.
 
-use chrono::{Duration, NaiveDate, NaiveTime};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use chrono::{Datelike, Duration, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone, Timelike, Utc};
+use chrono_tz::Tz;
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::float::Float;
 use serde::{Deserialize, Serialize};
 
-use crate::CalendarError;
+use crate::calendar::HebrewMonth;
+use crate::holidays::{Holiday, HolidayCalculator, HolidayScheme};
+use crate::{CalendarError, DateConverter};
+
+/// Well-known cities as (name, latitude, longitude)
+pub const KNOWN_CITIES: &[(&str, f64, f64)] = &[
+    ("Jerusalem", 31.7683, 35.2137),
+    ("Tel Aviv", 32.0853, 34.7818),
+    ("Haifa", 32.7940, 34.9896),
+    ("Beer Sheva", 31.2520, 34.7915),
+    ("New York", 40.7128, -74.0060),
+    ("Los Angeles", 34.0522, -118.2437),
+    ("Chicago", 41.8781, -87.6298),
+    ("Miami", 25.7617, -80.1918),
+    ("Toronto", 43.6532, -79.3832),
+    ("Montreal", 45.5017, -73.5673),
+    ("London", 51.5074, -0.1278),
+    ("Paris", 48.8566, 2.3522),
+    ("Antwerp", 51.2194, 4.4025),
+    ("Moscow", 55.7558, 37.6173),
+    ("Johannesburg", -26.2041, 28.0473),
+    ("Buenos Aires", -34.6037, -58.3816),
+    ("Melbourne", -37.8136, 144.9631),
+    ("Sydney", -33.8688, 151.2093),
+];
+
+/// Mean Earth radius used for great-circle distances
+const EARTH_RADIUS_KM: f64 = 6371.0;
 
 /// Geographic location for zmanim calculations
+///
+/// Deserializing runs `validate`, so out-of-range coordinates are rejected
+/// at parse time.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "GeoLocationFields")]
 pub struct GeoLocation {
     pub latitude: f64,
     pub longitude: f64,
     pub elevation_meters: f64,
     pub timezone_offset_minutes: i32,
+    /// IANA time zone (e.g. "America/New_York"). When set, it takes precedence
+    /// over `timezone_offset_minutes` and DST is applied per event.
+    #[serde(default)]
+    pub timezone_id: Option<String>,
     pub location_name: Option<String>,
+    /// Community "light by" clock time; candle lighting is never later than this
+    #[serde(default)]
+    pub fixed_candle_time: Option<NaiveTime>,
+    /// Offer "early Shabbat" (lighting at plag hamincha) on Fridays whose
+    /// regular candle lighting is later than this time
+    #[serde(default)]
+    pub early_shabbat_threshold: Option<NaiveTime>,
+}
+
+/// Unvalidated mirror of `GeoLocation`, the raw input to its `Deserialize`
+#[derive(Deserialize)]
+struct GeoLocationFields {
+    latitude: f64,
+    longitude: f64,
+    elevation_meters: f64,
+    timezone_offset_minutes: i32,
+    #[serde(default)]
+    timezone_id: Option<String>,
+    location_name: Option<String>,
+    #[serde(default)]
+    fixed_candle_time: Option<NaiveTime>,
+    #[serde(default)]
+    early_shabbat_threshold: Option<NaiveTime>,
+}
+
+/// An angle in degrees reduced to [0, 360)
+///
+/// `f64::rem_euclid` needs std; this is the same operation with plain `%`.
+fn wrap_degrees(degrees: f64) -> f64 {
+    let rem = degrees % 360.0;
+    if rem < 0.0 { rem + 360.0 } else { rem }
+}
+
+/// Elevations from the Dead Sea shore to above Everest are accepted
+fn check_elevation(elevation: f64) -> Result<(), CalendarError> {
+    if !(-500.0..=9000.0).contains(&elevation) {
+        return Err(CalendarError::InvalidElevation(elevation));
+    }
+    Ok(())
+}
+
+/// UTC offsets in use run from -12:00 to +14:00
+fn check_offset(offset_minutes: i32) -> Result<(), CalendarError> {
+    if !(-720..=840).contains(&offset_minutes) {
+        return Err(CalendarError::InvalidTimezone(
+            format!("UTC offset of {} minutes", offset_minutes)
+        ));
+    }
+    Ok(())
+}
+
+/// The antimeridian is stored as 180, never -180, so equal places compare equal
+fn normalize_longitude(longitude: f64) -> f64 {
+    if longitude == -180.0 { 180.0 } else { longitude }
+}
+
+impl TryFrom<GeoLocationFields> for GeoLocation {
+    type Error = CalendarError;
+    
+    fn try_from(fields: GeoLocationFields) -> Result<Self, Self::Error> {
+        let location = GeoLocation {
+            latitude: fields.latitude,
+            longitude: normalize_longitude(fields.longitude),
+            elevation_meters: fields.elevation_meters,
+            timezone_offset_minutes: fields.timezone_offset_minutes,
+            timezone_id: fields.timezone_id,
+            location_name: fields.location_name,
+            fixed_candle_time: fields.fixed_candle_time,
+            early_shabbat_threshold: fields.early_shabbat_threshold,
+        };
+        location.validate()?;
+        Ok(location)
+    }
 }
 
 impl GeoLocation {
     pub fn new(latitude: f64, longitude: f64) -> Result<Self, CalendarError> {
-        if latitude < -90.0 || latitude > 90.0 {
+        if !(-90.0..=90.0).contains(&latitude) {
             return Err(CalendarError::InvalidLatitude(latitude));
         }
-        if longitude < -180.0 || longitude > 180.0 {
+        if !(-180.0..=180.0).contains(&longitude) {
             return Err(CalendarError::InvalidLongitude(longitude));
         }
         
         Ok(Self {
             latitude,
-            longitude,
+            longitude: normalize_longitude(longitude),
             elevation_meters: 0.0,
             timezone_offset_minutes: 0,
+            timezone_id: None,
             location_name: None,
+            fixed_candle_time: None,
+            early_shabbat_threshold: None,
         })
     }
     
-    pub fn with_elevation(mut self, elevation: f64) -> Self {
+    /// Parse a location from JSON (e.g. from a browser or FFI caller), with
+    /// the same checks as `new` plus elevation and time zone
+    #[cfg(feature = "json")]
+    pub fn from_json(json: &str) -> Result<Self, CalendarError> {
+        let fields: GeoLocationFields = serde_json::from_str(json)
+            .map_err(|e| CalendarError::InvalidJson(e.to_string()))?;
+        Self::try_from(fields)
+    }
+    
+    /// Check coordinates, elevation and time zone are in range
+    pub fn validate(&self) -> Result<(), CalendarError> {
+        if !(-90.0..=90.0).contains(&self.latitude) {
+            return Err(CalendarError::InvalidLatitude(self.latitude));
+        }
+        if !(-180.0..=180.0).contains(&self.longitude) {
+            return Err(CalendarError::InvalidLongitude(self.longitude));
+        }
+        check_elevation(self.elevation_meters)?;
+        check_offset(self.timezone_offset_minutes)?;
+        if let Some(id) = &self.timezone_id {
+            id.parse::<Tz>().map_err(|_| CalendarError::InvalidTimezone(id.clone()))?;
+        }
+        Ok(())
+    }
+    
+    /// Set the elevation, checked with the same bounds as `validate`
+    pub fn with_elevation(mut self, elevation: f64) -> Result<Self, CalendarError> {
+        check_elevation(elevation)?;
         self.elevation_meters = elevation;
-        self
+        Ok(self)
     }
     
-    pub fn with_timezone(mut self, offset_minutes: i32) -> Self {
+    /// Set a fixed UTC offset, checked with the same bounds as `validate`
+    pub fn with_timezone(mut self, offset_minutes: i32) -> Result<Self, CalendarError> {
+        check_offset(offset_minutes)?;
         self.timezone_offset_minutes = offset_minutes;
-        self
+        Ok(self)
+    }
+    
+    /// Use an IANA time zone so that DST transitions are respected
+    pub fn with_timezone_id(mut self, timezone_id: &str) -> Result<Self, CalendarError> {
+        timezone_id.parse::<Tz>()
+            .map_err(|_| CalendarError::InvalidTimezone(timezone_id.to_string()))?;
+        self.timezone_id = Some(timezone_id.to_string());
+        Ok(self)
     }
     
     pub fn with_name(mut self, name: impl Into<String>) -> Self {
@@ -51,6 +205,67 @@ impl GeoLocation {
         self
     }
     
+    /// Light candles no later than a fixed clock time (e.g. 19:00 in summer)
+    pub fn with_fixed_candle_time(mut self, time: NaiveTime) -> Self {
+        self.fixed_candle_time = Some(time);
+        self
+    }
+    
+    /// Offer early Shabbat at plag when candle lighting is later than `time`
+    pub fn with_early_shabbat_threshold(mut self, time: NaiveTime) -> Self {
+        self.early_shabbat_threshold = Some(time);
+        self
+    }
+    
+    /// Quantized key for hashing a location
+    ///
+    /// Latitude and longitude are rounded to `precision` decimal places
+    /// (6 = microdegrees); returns (lat, long, timezone offset, IANA zone,
+    /// elevation in meters, fixed candle time, early Shabbat threshold), since
+    /// the zone shifts every time and the last two change the candle lighting.
+    pub fn cache_key(&self, precision: u32) -> (i64, i64, i32, Option<String>, i64, Option<NaiveTime>, Option<NaiveTime>) {
+        let scale = 10f64.powi(precision as i32);
+        (
+            (self.latitude * scale).round() as i64,
+            (self.longitude * scale).round() as i64,
+            self.timezone_offset_minutes,
+            self.timezone_id.clone(),
+            self.elevation_meters.round() as i64,
+            self.fixed_candle_time,
+            self.early_shabbat_threshold,
+        )
+    }
+    
+    /// Great-circle (haversine) distance to another location in kilometers
+    pub fn distance_km(&self, other: &GeoLocation) -> f64 {
+        haversine_km(self.latitude, self.longitude, other.latitude, other.longitude)
+    }
+    
+    /// The closest city in `KNOWN_CITIES` and its distance in kilometers
+    pub fn nearest_known_city(&self) -> (&'static str, f64) {
+        KNOWN_CITIES.iter()
+            .map(|&(name, lat, lng)| (name, haversine_km(self.latitude, self.longitude, lat, lng)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap_or(("", f64::INFINITY))
+    }
+    
+    /// The parsed IANA time zone, if one is set
+    fn tz(&self) -> Option<Tz> {
+        self.timezone_id.as_deref().and_then(|id| id.parse().ok())
+    }
+    
+    /// UTC offset in force at a local wall-clock time here
+    ///
+    /// Follows the IANA zone (and so DST) when one is set, otherwise the
+    /// fixed `timezone_offset_minutes`.
+    pub fn utc_offset_at(&self, local: NaiveDateTime) -> FixedOffset {
+        match self.tz() {
+            Some(tz) => ZmanimCalculator::resolve_in_zone(&tz, local).offset().fix(),
+            None => FixedOffset::east_opt(self.timezone_offset_minutes * 60)
+                .unwrap_or_else(|| Utc.fix()),
+        }
+    }
+    
     /// Create a location for Jerusalem
     pub fn jerusalem() -> Self {
         Self {
@@ -58,7 +273,10 @@ impl GeoLocation {
             longitude: 35.2137,
             elevation_meters: 754.0,
             timezone_offset_minutes: 120, // UTC+2 (standard), +3 in summer
+            timezone_id: None,
             location_name: Some("Jerusalem".to_string()),
+            fixed_candle_time: None,
+            early_shabbat_threshold: None,
         }
     }
     
@@ -69,11 +287,23 @@ impl GeoLocation {
             longitude: -74.0060,
             elevation_meters: 10.0,
             timezone_offset_minutes: -300, // UTC-5 (EST)
+            timezone_id: None,
             location_name: Some("New York".to_string()),
+            fixed_candle_time: None,
+            early_shabbat_threshold: None,
         }
     }
 }
 
+/// Haversine distance between two coordinates in kilometers
+fn haversine_km(lat1: f64, lng1: f64, lat2: f64, lng2: f64) -> f64 {
+    let d_lat = (lat2 - lat1).to_radians();
+    let d_lng = (lng2 - lng1).to_radians();
+    let a = (d_lat / 2.0).sin().powi(2)
+        + lat1.to_radians().cos() * lat2.to_radians().cos() * (d_lng / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+}
+
 /// Zmanim for a specific day
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Zmanim {
@@ -93,17 +323,289 @@ pub struct Zmanim {
     pub sunset: Option<String>,            // Shkiah
     pub tzeit_hakochavim: Option<String>, // Nightfall (8.5° below horizon)
     pub tzeit_72_min: Option<String>,      // 72 minutes after sunset
+    /// Longitude-implied mean solar time offset from UTC (set by `calculate_utc`)
+    #[serde(default)]
+    pub mean_time_offset_minutes: Option<i32>,
+    /// Which civil day each time falls on, relative to `date`
+    #[serde(default)]
+    pub day_offsets: ZmanimDayOffsets,
+    /// Sunset to the second ("HH:MM:SS"), so candle lighting can round earlier
+    #[serde(default)]
+    pub sunset_exact: Option<String>,
+    /// Which angle-based times could not be computed
+    #[serde(default)]
+    pub reliability: ZmanimReliability,
+}
+
+impl Zmanim {
+    /// All present times as (label, time), in chronological order
+    ///
+    /// Times past midnight (day offset +1) sort after the evening times.
+    pub fn timeline(&self) -> Vec<(&'static str, String)> {
+        let offsets = &self.day_offsets;
+        let entries = [
+            ("Alot HaShachar", &self.alot_hashachar, offsets.alot_hashachar),
+            ("Misheyakir", &self.misheyakir, offsets.misheyakir),
+            ("Sunrise", &self.sunrise, offsets.sunrise),
+            ("Sof Zman Shema (MGA)", &self.sof_zman_shema_mga, offsets.sof_zman_shema_mga),
+            ("Sof Zman Shema (GRA)", &self.sof_zman_shema_gra, offsets.sof_zman_shema_gra),
+            ("Sof Zman Tefila (MGA)", &self.sof_zman_tefila_mga, offsets.sof_zman_tefila_mga),
+            ("Sof Zman Tefila (GRA)", &self.sof_zman_tefila_gra, offsets.sof_zman_tefila_gra),
+            ("Chatzot", &self.chatzot, offsets.chatzot),
+            ("Mincha Gedola", &self.mincha_gedola, offsets.mincha_gedola),
+            ("Mincha Ketana", &self.mincha_ketana, offsets.mincha_ketana),
+            ("Plag HaMincha", &self.plag_hamincha, offsets.plag_hamincha),
+            ("Sunset", &self.sunset, offsets.sunset),
+            ("Tzeit HaKochavim", &self.tzeit_hakochavim, offsets.tzeit_hakochavim),
+            ("Tzeit (72 min)", &self.tzeit_72_min, offsets.tzeit_72_min),
+        ];
+        
+        let mut present: Vec<(i8, &'static str, String)> = entries.into_iter()
+            .filter_map(|(label, time, offset)| time.clone().map(|t| (offset, label, t)))
+            .collect();
+        // "HH:MM" strings order correctly within a day; the stable sort keeps
+        // the listed order for equal times
+        present.sort_by(|a, b| (a.0, &a.2).cmp(&(b.0, &b.2)));
+        
+        present.into_iter().map(|(_, label, time)| (label, time)).collect()
+    }
+    
+    /// Every zman as a full local date and time
+    ///
+    /// Each time is attached to its civil date using `day_offsets`, so a
+    /// tzeit after midnight carries the following day's date.
+    pub fn as_datetimes(&self) -> ZmanimDateTimes {
+        let date = NaiveDate::parse_from_str(&self.date, "%Y-%m-%d").ok();
+        let at = |time: &Option<String>, offset: i8| -> Option<NaiveDateTime> {
+            let time = NaiveTime::parse_from_str(time.as_ref()?, "%H:%M").ok()?;
+            Some((date? + Duration::days(offset as i64)).and_time(time))
+        };
+        let offsets = &self.day_offsets;
+        
+        ZmanimDateTimes {
+            alot_hashachar: at(&self.alot_hashachar, offsets.alot_hashachar),
+            misheyakir: at(&self.misheyakir, offsets.misheyakir),
+            sunrise: at(&self.sunrise, offsets.sunrise),
+            sof_zman_shema_mga: at(&self.sof_zman_shema_mga, offsets.sof_zman_shema_mga),
+            sof_zman_shema_gra: at(&self.sof_zman_shema_gra, offsets.sof_zman_shema_gra),
+            sof_zman_tefila_mga: at(&self.sof_zman_tefila_mga, offsets.sof_zman_tefila_mga),
+            sof_zman_tefila_gra: at(&self.sof_zman_tefila_gra, offsets.sof_zman_tefila_gra),
+            chatzot: at(&self.chatzot, offsets.chatzot),
+            mincha_gedola: at(&self.mincha_gedola, offsets.mincha_gedola),
+            mincha_ketana: at(&self.mincha_ketana, offsets.mincha_ketana),
+            plag_hamincha: at(&self.plag_hamincha, offsets.plag_hamincha),
+            sunset: at(&self.sunset, offsets.sunset),
+            tzeit_hakochavim: at(&self.tzeit_hakochavim, offsets.tzeit_hakochavim),
+            tzeit_72_min: at(&self.tzeit_72_min, offsets.tzeit_72_min),
+        }
+    }
+}
+
+/// Zmanim as full local date-times (see `Zmanim::as_datetimes`)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ZmanimDateTimes {
+    pub alot_hashachar: Option<NaiveDateTime>,
+    pub misheyakir: Option<NaiveDateTime>,
+    pub sunrise: Option<NaiveDateTime>,
+    pub sof_zman_shema_mga: Option<NaiveDateTime>,
+    pub sof_zman_shema_gra: Option<NaiveDateTime>,
+    pub sof_zman_tefila_mga: Option<NaiveDateTime>,
+    pub sof_zman_tefila_gra: Option<NaiveDateTime>,
+    pub chatzot: Option<NaiveDateTime>,
+    pub mincha_gedola: Option<NaiveDateTime>,
+    pub mincha_ketana: Option<NaiveDateTime>,
+    pub plag_hamincha: Option<NaiveDateTime>,
+    pub sunset: Option<NaiveDateTime>,
+    pub tzeit_hakochavim: Option<NaiveDateTime>,
+    pub tzeit_72_min: Option<NaiveDateTime>,
+}
+
+/// Calendar-day offset of each zman relative to the requested date
+///
+/// 0 is the same civil day, +1 means the time is after midnight (e.g. a
+/// summer tzeit at high latitudes) and -1 means the evening before.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ZmanimDayOffsets {
+    pub alot_hashachar: i8,
+    pub misheyakir: i8,
+    pub sunrise: i8,
+    pub sof_zman_shema_mga: i8,
+    pub sof_zman_shema_gra: i8,
+    pub sof_zman_tefila_mga: i8,
+    pub sof_zman_tefila_gra: i8,
+    pub chatzot: i8,
+    pub mincha_gedola: i8,
+    pub mincha_ketana: i8,
+    pub plag_hamincha: i8,
+    pub sunset: i8,
+    pub tzeit_hakochavim: i8,
+    pub tzeit_72_min: i8,
+}
+
+/// Whether every angle-based zman could be computed for the day
+///
+/// At high latitudes in summer the sun may not sink to 16.1° (or even
+/// 8.5°) while sunrise and sunset still occur, leaving the times that
+/// depend on those angles empty. Apps can use this to show a disclaimer.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "ReliabilityRepr")]
+pub enum ZmanimReliability {
+    #[default]
+    Reliable,
+    /// Field names of the angle-based times that never occur on this day
+    PartialMissingAngles(Vec<&'static str>),
+}
+
+/// Angle-based zmanim, by field name
+const ANGLE_BASED_ZMANIM: [&str; 5] =
+    ["alot_hashachar", "misheyakir", "sunrise", "sunset", "tzeit_hakochavim"];
+
+/// Owned mirror of `ZmanimReliability` for deserializing
+#[derive(Deserialize)]
+enum ReliabilityRepr {
+    Reliable,
+    PartialMissingAngles(Vec<String>),
+}
+
+impl TryFrom<ReliabilityRepr> for ZmanimReliability {
+    type Error = String;
+    
+    fn try_from(repr: ReliabilityRepr) -> Result<Self, Self::Error> {
+        match repr {
+            ReliabilityRepr::Reliable => Ok(ZmanimReliability::Reliable),
+            ReliabilityRepr::PartialMissingAngles(names) => names.iter()
+                .map(|name| ANGLE_BASED_ZMANIM.iter().copied().find(|known| known == name)
+                    .ok_or_else(|| format!("Unknown angle-based zman: {}", name)))
+                .collect::<Result<_, _>>()
+                .map(ZmanimReliability::PartialMissingAngles),
+        }
+    }
+}
+
+/// How the Magen Avraham day (alot to tzeit) is bounded
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MgaBasis {
+    /// 72 fixed minutes before sunrise and after sunset
+    #[default]
+    Fixed72,
+    /// 72 proportional minutes (1.2 shaot zmaniyot) before sunrise and after sunset
+    Zmaniyos72,
+    /// Sun 16.1° below the horizon, morning and evening
+    Degrees16_1,
+}
+
+/// Chametz deadlines for erev Pesach (14 Nisan), by the Magen Avraham day
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErevPesachTimes {
+    pub date: String,
+    /// Stop eating chametz: end of the 4th hour
+    pub sof_zman_achilat_chametz: Option<String>,
+    /// Burn chametz: end of the 5th hour
+    pub sof_zman_biur_chametz: Option<String>,
+}
+
+/// Intermediate solar values for a date, for diagnosing zmanim discrepancies
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SolarDebug {
+    /// Sun declination at solar noon (degrees)
+    pub declination_deg: f64,
+    /// Apparent minus mean solar time (minutes)
+    pub equation_of_time_min: f64,
+    /// Local time of solar noon, to the second
+    pub solar_noon_local: NaiveDateTime,
+}
+
+/// When a minor (dawn-to-nightfall) fast begins
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FastStart {
+    /// Alot hashachar
+    #[default]
+    Alot,
+    /// Sunrise, as some calendars print it
+    Sunrise,
+}
+
+/// How a dawn-side zman is defined: a solar depression angle or a fixed offset
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ZmanThreshold {
+    /// Sun this many degrees below the horizon (e.g. 11.5)
+    Degrees(f64),
+    /// This many clock minutes before sunrise
+    MinutesBeforeSunrise(i64),
+}
+
+impl Default for ZmanThreshold {
+    /// Misheyakir at 11.5°
+    fn default() -> Self {
+        ZmanThreshold::Degrees(11.5)
+    }
+}
+
+/// Options controlling how zmanim are calculated and displayed
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ZmanimConfig {
+    /// Show times in this UTC offset (minutes) instead of the location's zone.
+    /// Solar events are still computed for the location's coordinates.
+    pub display_timezone_offset: Option<i32>,
+    /// Day boundaries used for the Magen Avraham sof zman shema/tefila
+    #[serde(default)]
+    pub mga_basis: MgaBasis,
+    /// Reference solar noon to this meridian (degrees east, e.g. 15.0 for
+    /// CET) instead of the observer's longitude. The default already gives
+    /// civil clock time at the observer; this yields the zone-meridian
+    /// times some printed calendars use.
+    #[serde(default)]
+    pub reference_meridian: Option<f64>,
+    /// Angle (degrees) of the visible horizon above (+) or below (-) the
+    /// astronomical horizon, e.g. a mountain ridge to the west. Applied to
+    /// sunrise and sunset only; separate from observer elevation.
+    #[serde(default)]
+    pub horizon_elevation_angle: Option<f64>,
+    /// Refine solar events with nutation, aberration and the sun's position
+    /// at the event itself (a few seconds) rather than at noon (~1 minute)
+    #[serde(default)]
+    pub high_precision: bool,
+    /// Earliest tallit and tefillin (misheyakir); 11.5° by default
+    #[serde(default)]
+    pub misheyakir: ZmanThreshold,
+    /// When minor fasts begin; Tisha B'Av and Yom Kippur always begin at sunset
+    #[serde(default)]
+    pub minor_fast_start: FastStart,
+    /// Floor candle lighting to a multiple of this many minutes (e.g. 5), as
+    /// some luachs print it; 0 or 1 keeps whole minutes
+    #[serde(default)]
+    pub candle_rounding_minutes: u8,
 }
 
 /// Zmanim calculator
 pub struct ZmanimCalculator {
     location: GeoLocation,
+    config: ZmanimConfig,
 }
 
 impl ZmanimCalculator {
     /// Create a new calculator for a location
     pub fn new(location: GeoLocation) -> Self {
-        Self { location }
+        Self { location, config: ZmanimConfig::default() }
+    }
+    
+    pub fn with_config(mut self, config: ZmanimConfig) -> Self {
+        self.config = config;
+        self
+    }
+    
+    /// The IANA zone used for output, unless a display offset overrides it
+    fn tz(&self) -> Option<Tz> {
+        match self.config.display_timezone_offset {
+            Some(_) => None,
+            None => self.location.tz(),
+        }
+    }
+    
+    /// The fixed UTC offset (minutes) used for output without an IANA zone
+    fn offset_minutes(&self) -> i32 {
+        self.config.display_timezone_offset
+            .unwrap_or(self.location.timezone_offset_minutes)
     }
     
     /// Calculate all zmanim for a date
@@ -127,26 +629,213 @@ impl ZmanimCalculator {
             sunset: times.sunset.map(|t| t.format("%H:%M").to_string()),
             tzeit_hakochavim: times.tzeit.map(|t| t.format("%H:%M").to_string()),
             tzeit_72_min: times.tzeit_72.map(|t| t.format("%H:%M").to_string()),
+            mean_time_offset_minutes: None,
+            day_offsets: times.day_offsets(date),
+            sunset_exact: times.sunset_exact.map(|t| t.format("%H:%M:%S").to_string()),
+            reliability: times.reliability(),
+        })
+    }
+    
+    /// Calculate all zmanim as local date-times, without formatting strings
+    ///
+    /// The times behind `calculate`, before they are cut to "HH:MM", for
+    /// callers such as `no_std` clocks that render the numbers themselves.
+    pub fn calculate_datetimes(&self, date: NaiveDate) -> Result<ZmanimDateTimes, CalendarError> {
+        let times = self.calculate_times(date)?;
+        
+        Ok(ZmanimDateTimes {
+            alot_hashachar: times.alot,
+            misheyakir: times.misheyakir,
+            sunrise: times.sunrise,
+            sof_zman_shema_mga: times.sof_shema_mga,
+            sof_zman_shema_gra: times.sof_shema_gra,
+            sof_zman_tefila_mga: times.sof_tefila_mga,
+            sof_zman_tefila_gra: times.sof_tefila_gra,
+            chatzot: times.chatzot,
+            mincha_gedola: times.mincha_gedola,
+            mincha_ketana: times.mincha_ketana,
+            plag_hamincha: times.plag,
+            sunset: times.sunset,
+            tzeit_hakochavim: times.tzeit,
+            tzeit_72_min: times.tzeit_72,
         })
     }
     
+    /// Calculate zmanim for the Sunday-through-Shabbat week containing a date
+    pub fn week_of(&self, any_date_in_week: NaiveDate) -> Result<[Zmanim; 7], CalendarError> {
+        let sunday = any_date_in_week
+            - Duration::days(any_date_in_week.weekday().num_days_from_sunday() as i64);
+        
+        let week = (0..7)
+            .map(|i| self.calculate(sunday + Duration::days(i)))
+            .collect::<Result<Vec<_>, _>>()?;
+        week.try_into()
+            .map_err(|_| CalendarError::CalculationError("Expected seven days of zmanim".to_string()))
+    }
+    
+    /// Calculate all zmanim in UTC, with the longitude-implied offset
+    ///
+    /// For callers that only have coordinates: the times are UTC and
+    /// `mean_time_offset_minutes` (longitude × 4 minutes) can be applied
+    /// later to get local mean solar time.
+    pub fn calculate_utc(&self, date: NaiveDate) -> Result<Zmanim, CalendarError> {
+        let mut config = self.config.clone();
+        config.display_timezone_offset = Some(0);
+        
+        let utc = ZmanimCalculator::new(self.location.clone()).with_config(config);
+        let mut zmanim = utc.calculate(date)?;
+        zmanim.mean_time_offset_minutes = Some((self.location.longitude * 4.0).round() as i32);
+        
+        Ok(zmanim)
+    }
+    
+    /// Chametz deadlines for erev Pesach
+    ///
+    /// The hours follow the configured `mga_basis`. Returns None unless `date`
+    /// falls on 14 Nisan.
+    pub fn erev_pesach_times(&self, date: NaiveDate) -> Result<Option<ErevPesachTimes>, CalendarError> {
+        let hebrew = DateConverter::gregorian_to_hebrew(date)?;
+        if (hebrew.month, hebrew.day) != (HebrewMonth::Nisan, 14) {
+            return Ok(None);
+        }
+        
+        let times = self.calculate_times(date)?;
+        // Sof zman shema and tefila end the 3rd and 4th MGA hours
+        let biur = times.sof_shema_mga.zip(times.sof_tefila_mga)
+            .map(|(shema, tefila)| tefila + (tefila - shema));
+        
+        Ok(Some(ErevPesachTimes {
+            date: date.to_string(),
+            sof_zman_achilat_chametz: times.sof_tefila_mga.map(|t| t.format("%H:%M").to_string()),
+            sof_zman_biur_chametz: biur.map(|t| t.format("%H:%M").to_string()),
+        }))
+    }
+    
+    /// Start and end of a fast observed on `date`, as (begins, ends)
+    ///
+    /// Minor fasts run from alot hashachar (or sunrise, per `minor_fast_start`)
+    /// to tzeit; Tisha B'Av and Yom Kippur begin at sunset the evening before.
+    /// Returns None if `fast` is not a fast or is not observed on `date`; a
+    /// fast that falls on Shabbat is observed on its postponed (or, for Taanit
+    /// Esther, advanced) day.
+    pub fn fast_times(&self, date: NaiveDate, fast: Holiday) -> Result<Option<(String, String)>, CalendarError> {
+        if !fast.is_fast_day() {
+            return Ok(None);
+        }
+        let hebrew = DateConverter::gregorian_to_hebrew(date)?;
+        if HolidayCalculator::hebrew_date_of(fast, hebrew.year, HolidayScheme::Diaspora)? != hebrew {
+            return Ok(None);
+        }
+        
+        let zmanim = self.calculate(date)?;
+        let begins = match fast {
+            Holiday::TishaBAv | Holiday::YomKippur => {
+                let eve = date.pred_opt()
+                    .ok_or_else(|| CalendarError::date_out_of_range(date))?;
+                self.calculate(eve)?.sunset
+            }
+            _ => match self.config.minor_fast_start {
+                FastStart::Alot => zmanim.alot_hashachar,
+                FastStart::Sunrise => zmanim.sunrise,
+            },
+        };
+        
+        Ok(begins.zip(zmanim.tzeit_hakochavim))
+    }
+    
     /// Calculate candle lighting time
+    ///
+    /// Always rounds down to the minute (from `sunset_exact` when present), so
+    /// candle lighting is never later than sunset minus the offset. With a
+    /// location `fixed_candle_time`, the earlier of the two times is used.
     pub fn candle_lighting(
         &self,
         zmanim: &Zmanim,
         offset_minutes: i64,
+    ) -> Result<Option<String>, CalendarError> {
+        let sunset_time = match (&zmanim.sunset_exact, &zmanim.sunset) {
+            (Some(exact), _) => NaiveTime::parse_from_str(exact, "%H:%M:%S"),
+            (None, Some(rounded)) => NaiveTime::parse_from_str(rounded, "%H:%M"),
+            (None, None) => return Ok(None),
+        }
+        .map_err(|e| CalendarError::CalculationError(e.to_string()))?;
+        
+        // %H:%M truncates the seconds, i.e. floors to the minute
+        let candle_time = sunset_time - Duration::minutes(offset_minutes);
+        let candle_time = match self.location.fixed_candle_time {
+            Some(fixed) => candle_time.min(fixed),
+            None => candle_time,
+        };
+        
+        // Round down so the printed time is never later than the computed one
+        let step = self.config.candle_rounding_minutes.max(1) as u32;
+        let candle_time = candle_time - Duration::minutes((candle_time.minute() % step) as i64);
+        
+        Ok(Some(candle_time.format("%H:%M").to_string()))
+    }
+    
+    /// Calculate Havdalah time (sunset plus offset)
+    ///
+    /// With an IANA time zone the offset is added in elapsed time, so a
+    /// Havdalah that crosses a spring-forward transition skips the lost hour.
+    pub fn havdalah(
+        &self,
+        zmanim: &Zmanim,
+        offset_minutes: i64,
     ) -> Result<Option<String>, CalendarError> {
         let sunset_str = match &zmanim.sunset {
             Some(s) => s,
             None => return Ok(None),
         };
         
+        let date = NaiveDate::parse_from_str(&zmanim.date, "%Y-%m-%d")
+            .map_err(|e| CalendarError::CalculationError(e.to_string()))?;
         let sunset_time = NaiveTime::parse_from_str(sunset_str, "%H:%M")
             .map_err(|e| CalendarError::CalculationError(e.to_string()))?;
         
-        let candle_time = sunset_time - Duration::minutes(offset_minutes);
+        let havdalah_time = self.add_elapsed_minutes(date.and_time(sunset_time), offset_minutes);
         
-        Ok(Some(candle_time.format("%H:%M").to_string()))
+        Ok(Some(havdalah_time.format("%H:%M").to_string()))
+    }
+    
+    /// Resolve a wall-clock time onto a valid local time
+    ///
+    /// Times that fall in a spring-forward gap are moved to the
+    /// post-transition time (e.g. 02:30 becomes 03:30); ambiguous
+    /// fall-back times resolve to the earlier instant. Without an IANA
+    /// time zone the input is returned unchanged.
+    pub fn resolve_wall_time(&self, local: NaiveDateTime) -> NaiveDateTime {
+        match self.tz() {
+            Some(tz) => Self::resolve_in_zone(&tz, local).naive_local(),
+            None => local,
+        }
+    }
+    
+    /// Map a local time in `tz` to an instant, moving gap times forward
+    fn resolve_in_zone(tz: &Tz, local: NaiveDateTime) -> chrono::DateTime<Tz> {
+        match tz.from_local_datetime(&local) {
+            LocalResult::Single(t) => t,
+            LocalResult::Ambiguous(earliest, _) => earliest,
+            LocalResult::None => {
+                // Interpret with the offset in force before the gap
+                let before = tz.offset_from_local_datetime(&(local - Duration::days(1)))
+                    .earliest()
+                    .map(|o| o.fix())
+                    .unwrap_or_else(|| tz.offset_from_utc_datetime(&local).fix());
+                tz.from_utc_datetime(&(local - before))
+            }
+        }
+    }
+    
+    /// Add elapsed minutes to a local date and time
+    fn add_elapsed_minutes(&self, start: NaiveDateTime, minutes: i64) -> NaiveDateTime {
+        match self.tz() {
+            Some(tz) => {
+                let start = Self::resolve_in_zone(&tz, start);
+                (start + Duration::minutes(minutes)).naive_local()
+            }
+            None => start + Duration::minutes(minutes),
+        }
     }
     
     /// Calculate specific time for an elevation angle
@@ -156,32 +845,45 @@ impl ZmanimCalculator {
         elevation: f64,
         rising: bool,
     ) -> Result<Option<NaiveTime>, CalendarError> {
-        let rd = crate::calendar::DateConverter::gregorian_to_rd(date);
-        let jd = crate::calendar::DateConverter::rd_to_julian_day(rd) as f64;
-
         // Calculate solar position
-        let time = self.calculate_solar_time(jd, elevation, rising);
+        let time = self.calculate_solar_time(date, elevation, rising).map(|t| t.time());
         
         Ok(time)
     }
     
     /// Internal: Calculate all times for a date
     fn calculate_times(&self, date: NaiveDate) -> Result<CalculatedTimes, CalendarError> {
-        let rd = crate::calendar::DateConverter::gregorian_to_rd(date);
-        let jd = crate::calendar::DateConverter::rd_to_julian_day(rd) as f64;
+        // The hour-angle formula divides by cos(latitude), which vanishes at the pole
+        if self.location.latitude.abs() >= 90.0 {
+            return Err(CalendarError::PolarLocation(self.location.latitude));
+        }
         
-        // Calculate sunrise and sunset (0.833° below horizon for refraction)
-        let sunrise = self.calculate_solar_time(jd, -0.833, true);
-        let sunset = self.calculate_solar_time(jd, -0.833, false);
+        // Calculate sunrise and sunset (0.833° below horizon for refraction),
+        // shifted by any obstructing or dipped horizon
+        let horizon = -0.833 + self.config.horizon_elevation_angle.unwrap_or(0.0);
+        let sunrise = self.calculate_solar_time(date, horizon, true);
+        let sunset = self.calculate_solar_time(date, horizon, false);
+        let sunset_exact = self.solar_event(date, horizon, false, false);
         
         // Dawn (16.1° below horizon - Alot Hashachar)
-        let alot = self.calculate_solar_time(jd, -16.1, true);
+        let alot = self.calculate_solar_time(date, -16.1, true);
         
-        // Misheyakir (11.5° below horizon)
-        let misheyakir = self.calculate_solar_time(jd, -11.5, true);
+        // Misheyakir (11.5° below horizon unless configured)
+        let misheyakir = match self.config.misheyakir {
+            ZmanThreshold::Degrees(degrees) => self.calculate_solar_time(date, -degrees, true),
+            ZmanThreshold::MinutesBeforeSunrise(minutes) => {
+                sunrise.map(|sr| self.add_elapsed_minutes(sr, -minutes))
+            }
+        };
         
         // Tzeit (8.5° below horizon)
-        let tzeit = self.calculate_solar_time(jd, -8.5, false);
+        let tzeit = self.calculate_solar_time(date, -8.5, false);
+        
+        // The MGA day runs 16.1° to 16.1° under that basis
+        let mga_bounds_16_1 = match self.config.mga_basis {
+            MgaBasis::Degrees16_1 => Some((alot, self.calculate_solar_time(date, -16.1, false))),
+            _ => None,
+        };
         
         // Calculate derived times
         let (sof_shema_gra, sof_shema_mga, sof_tefila_gra, sof_tefila_mga, 
@@ -195,16 +897,23 @@ impl ZmanimCalculator {
                 
                 // Sof zman shema (3 hours)
                 let sof_shema_gra = sr + shaah * 3;
-                // Magen Avraham uses alot to tzeit (72 min)
-                let alot_72 = sr - Duration::minutes(72);
-                let tzeit_72_calc = ss + Duration::minutes(72);
-                let day_length_mga = tzeit_72_calc.signed_duration_since(alot_72);
-                let shaah_mga = day_length_mga / 12;
-                let sof_shema_mga = alot_72 + shaah_mga * 3;
+                // Magen Avraham uses alot to tzeit, per the configured basis
+                let (alot_mga, tzeit_mga) = match (self.config.mga_basis, mga_bounds_16_1) {
+                    (MgaBasis::Fixed72, _) => {
+                        (Some(sr - Duration::minutes(72)), Some(ss + Duration::minutes(72)))
+                    }
+                    (MgaBasis::Zmaniyos72, _) => (Some(sr - shaah * 6 / 5), Some(ss + shaah * 6 / 5)),
+                    (MgaBasis::Degrees16_1, Some(bounds)) => bounds,
+                    (MgaBasis::Degrees16_1, None) => (None, None),
+                };
+                let mga = alot_mga.zip(tzeit_mga).map(|(start, end)| {
+                    (start, end.signed_duration_since(start) / 12)
+                });
+                let sof_shema_mga = mga.map(|(start, shaah_mga)| start + shaah_mga * 3);
                 
                 // Sof zman tefila (4 hours)
                 let sof_tefila_gra = sr + shaah * 4;
-                let sof_tefila_mga = alot_72 + shaah_mga * 4;
+                let sof_tefila_mga = mga.map(|(start, shaah_mga)| start + shaah_mga * 4);
                 
                 // Chatzot (midday)
                 let chatzot_time = sr + day_length / 2;
@@ -218,10 +927,10 @@ impl ZmanimCalculator {
                 // Plag hamincha (10.75 hours)
                 let plag_time = sr + shaah * 10 + (shaah * 3) / 4;
                 
-                (Some(sof_shema_gra), Some(sof_shema_mga), 
-                 Some(sof_tefila_gra), Some(sof_tefila_mga),
+                (Some(sof_shema_gra), sof_shema_mga, 
+                 Some(sof_tefila_gra), sof_tefila_mga,
                  Some(chatzot_time), Some(mincha_g), Some(mincha_k), 
-                 Some(plag_time), Some(tzeit_72_calc))
+                 Some(plag_time), Some(self.add_elapsed_minutes(ss, 72)))
             } else {
                 (None, None, None, None, None, None, None, None, None)
             };
@@ -241,16 +950,118 @@ impl ZmanimCalculator {
             sunset,
             tzeit,
             tzeit_72,
+            sunset_exact,
         })
     }
     
-    /// Calculate solar time for a specific elevation angle
-    /// Uses standard NOAA solar calculator algorithm
-    fn calculate_solar_time(&self, jd: f64, elevation: f64, rising: bool) -> Option<NaiveTime> {
-        let tz = self.location.timezone_offset_minutes as f64 / 60.0;
+    /// Declination, equation of time and solar noon as used for this date
+    ///
+    /// With `high_precision` the values are re-evaluated at solar noon itself.
+    pub fn solar_debug(&self, date: NaiveDate) -> SolarDebug {
+        let rd = DateConverter::gregorian_to_rd(date);
+        let jd = DateConverter::rd_to_julian_day(rd) as f64;
+        let lng = self.config.reference_meridian.unwrap_or(self.location.longitude);
+        
+        let (mut sun_declin, mut eq_time) = Self::solar_position(jd);
+        if self.config.high_precision {
+            let noon_minutes = 720.0 - 4.0 * lng - eq_time;
+            (sun_declin, eq_time) = Self::solar_position_precise(jd - 0.5 + noon_minutes / 1440.0);
+        }
+        let noon_minutes = 720.0 - 4.0 * lng - eq_time;
+        
+        SolarDebug {
+            declination_deg: sun_declin.to_degrees(),
+            equation_of_time_min: eq_time,
+            solar_noon_local: self.utc_minutes_to_local(date, Duration::seconds((noon_minutes * 60.0).round() as i64)),
+        }
+    }
+    
+    /// Calculate the local date and time the sun reaches an elevation angle,
+    /// rounded to the nearest minute
+    fn calculate_solar_time(&self, date: NaiveDate, elevation: f64, rising: bool) -> Option<NaiveDateTime> {
+        self.solar_event(date, elevation, rising, true)
+    }
+    
+    /// Calculate the local date and time the sun reaches an elevation angle
+    /// Uses standard NOAA solar calculator algorithm, refined iteratively
+    /// when `high_precision` is configured. The UTC time is rounded to the
+    /// nearest minute or, without `whole_minutes`, to the nearest second.
+    fn solar_event(
+        &self,
+        date: NaiveDate,
+        elevation: f64,
+        rising: bool,
+        whole_minutes: bool,
+    ) -> Option<NaiveDateTime> {
+        let rd = DateConverter::gregorian_to_rd(date);
+        // Julian Day at noon UT of this date
+        let jd = DateConverter::rd_to_julian_day(rd) as f64;
         let lat = self.location.latitude;
-        let lng = self.location.longitude;
+        let lng = self.config.reference_meridian.unwrap_or(self.location.longitude);
+
+        // Minutes from UTC midnight at which the sun reaches `elevation`
+        let event_at = |sun_declin: f64, eq_time: f64| -> Option<f64> {
+            // Solar noon (minutes from midnight, UTC)
+            let solar_noon_min = 720.0 - 4.0 * lng - eq_time;
+
+            // Hour angle for the desired elevation
+            let lat_rad = lat.to_radians();
+            let elevation_rad = elevation.to_radians();
+            let cos_hour = (elevation_rad.sin() - lat_rad.sin() * sun_declin.sin())
+                / (lat_rad.cos() * sun_declin.cos());
+
+            // Check if sun reaches this elevation at this latitude (NaN fails too)
+            if !(-1.0..=1.0).contains(&cos_hour) {
+                return None;
+            }
+
+            let hour_angle_deg = cos_hour.acos().to_degrees();
+
+            // Time of event (minutes from midnight)
+            Some(if rising {
+                solar_noon_min - hour_angle_deg * 4.0
+            } else {
+                solar_noon_min + hour_angle_deg * 4.0
+            })
+        };
+
+        let (sun_declin, eq_time) = Self::solar_position(jd);
+        let mut event_minutes = event_at(sun_declin, eq_time)?;
+
+        // Re-evaluate the sun's position at the event itself rather than at noon
+        if self.config.high_precision {
+            for _ in 0..3 {
+                let (sun_declin, eq_time) = Self::solar_position_precise(jd - 0.5 + event_minutes / 1440.0);
+                event_minutes = event_at(sun_declin, eq_time)?;
+            }
+        }
+
+        let event = if whole_minutes {
+            Duration::minutes(event_minutes.round() as i64)
+        } else {
+            Duration::seconds((event_minutes * 60.0).round() as i64)
+        };
+
+        Some(self.utc_minutes_to_local(date, event))
+    }
+    
+    /// Local date and time of an instant given as an offset from UTC midnight
+    fn utc_minutes_to_local(&self, date: NaiveDate, event: Duration) -> NaiveDateTime {
+        let midnight = date.and_time(NaiveTime::MIN);
+
+        // With an IANA zone, localize the UTC instant so DST is applied
+        if let Some(tz) = self.tz() {
+            return tz.from_utc_datetime(&(midnight + event)).naive_local();
+        }
+
+        // Apply the fixed offset; the result may land on an adjacent day
+        midnight + event + Duration::minutes(self.offset_minutes() as i64)
+    }
 
+    /// Sun declination (radians) and equation of time (minutes) at a Julian Day
+    ///
+    /// Low-order NOAA approximation, accurate to about a minute.
+    fn solar_position(jd: f64) -> (f64, f64) {
         // Julian century from J2000.0
         let jc = (jd - 2451545.0) / 36525.0;
 
@@ -297,55 +1108,126 @@ impl ZmanimCalculator {
             - 1.25 * ecc * ecc * (2.0 * gm_anom_rad).sin()
         ).to_degrees();
 
-        // Solar noon (minutes from midnight, local time)
-        let solar_noon_min = 720.0 - 4.0 * lng - eq_time + tz * 60.0;
+        (sun_declin, eq_time)
+    }
 
-        // Hour angle for the desired elevation
-        let lat_rad = lat.to_radians();
-        let elevation_rad = elevation.to_radians();
-        let cos_hour = (elevation_rad.sin() - lat_rad.sin() * sun_declin.sin())
-            / (lat_rad.cos() * sun_declin.cos());
+    /// Sun declination (radians) and equation of time (minutes) at a Julian Day
+    ///
+    /// Follows Meeus, "Astronomical Algorithms" ch. 22, 25 and 28: includes
+    /// nutation in longitude and obliquity, aberration, and the true
+    /// obliquity. Evaluated at the event time this is good to a few seconds,
+    /// against about a minute for `solar_position` evaluated at noon.
+    fn solar_position_precise(jd: f64) -> (f64, f64) {
+        let t = (jd - 2451545.0) / 36525.0;
 
-        // Check if sun reaches this elevation at this latitude
-        if cos_hour < -1.0 || cos_hour > 1.0 {
-            return None;
-        }
+        // Mean longitude and mean anomaly of the sun (degrees)
+        let l0 = wrap_degrees(280.46646 + t * (36000.76983 + t * 0.0003032));
+        let m = (357.52911 + t * (35999.05029 - t * 0.0001537)).to_radians();
+        let ecc = 0.016708634 - t * (0.000042037 + t * 0.0000001267);
 
-        let hour_angle_deg = cos_hour.acos().to_degrees();
+        // Equation of center and true longitude (degrees)
+        let c = m.sin() * (1.914602 - t * (0.004817 + t * 0.000014))
+            + (2.0 * m).sin() * (0.019993 - t * 0.000101)
+            + (3.0 * m).sin() * 0.000289;
+        let true_long = l0 + c;
+        let true_anom = m + c.to_radians();
 
-        // Time of event (minutes from midnight)
-        let event_minutes = if rising {
-            solar_noon_min - hour_angle_deg * 4.0
-        } else {
-            solar_noon_min + hour_angle_deg * 4.0
-        };
+        // Sun–Earth distance (AU), for aberration
+        let radius = 1.000001018 * (1.0 - ecc * ecc) / (1.0 + ecc * true_anom.cos());
+
+        // Nutation (arcseconds) from the moon's node and mean longitudes
+        let omega = (125.04452 - 1934.136261 * t).to_radians();
+        let l_sun = (280.4665 + 36000.7698 * t).to_radians();
+        let l_moon = (218.3165 + 481267.8813 * t).to_radians();
+        let nut_long = -17.20 * omega.sin() - 1.32 * (2.0 * l_sun).sin()
+            - 0.23 * (2.0 * l_moon).sin() + 0.21 * (2.0 * omega).sin();
+        let nut_obliq = 9.20 * omega.cos() + 0.57 * (2.0 * l_sun).cos()
+            + 0.10 * (2.0 * l_moon).cos() - 0.09 * (2.0 * omega).cos();
+
+        // Apparent longitude: nutation plus aberration (degrees)
+        let app_long = true_long + (nut_long - 20.4898 / radius) / 3600.0;
 
-        // Convert to hours and minutes, handling wrap-around
-        let total_minutes = event_minutes.round() as i64;
-        let total_minutes = total_minutes.rem_euclid(1440);
-        let hours = (total_minutes / 60) as u32;
-        let minutes = (total_minutes % 60) as u32;
+        // True obliquity: mean (Laskar) plus nutation (degrees)
+        let u = t / 100.0;
+        let mean_obliq_arcsec = 84381.448
+            + u * (-4680.93 + u * (-1.55 + u * (1999.25 + u * (-51.38 + u * (-249.67
+            + u * (-39.05 + u * (7.12 + u * (27.87 + u * (5.79 + u * 2.45)))))))));
+        let obliq = ((mean_obliq_arcsec + nut_obliq) / 3600.0).to_radians();
 
-        NaiveTime::from_hms_opt(hours, minutes, 0)
+        // Apparent right ascension and declination
+        let app_long_rad = app_long.to_radians();
+        let right_asc = (obliq.cos() * app_long_rad.sin())
+            .atan2(app_long_rad.cos())
+            .to_degrees();
+        let sun_declin = (obliq.sin() * app_long_rad.sin()).asin();
+
+        // Equation of time (degrees → minutes), wrapped to ±180°
+        let eq_deg = l0 - 0.0057183 - right_asc + nut_long / 3600.0 * obliq.cos();
+        let eq_time = 4.0 * (wrap_degrees(eq_deg + 180.0) - 180.0);
+
+        (sun_declin, eq_time)
     }
 }
 
 /// Internal structure for calculated times
 struct CalculatedTimes {
-    alot: Option<NaiveTime>,
-    misheyakir: Option<NaiveTime>,
-    sunrise: Option<NaiveTime>,
-    sof_shema_mga: Option<NaiveTime>,
-    sof_shema_gra: Option<NaiveTime>,
-    sof_tefila_mga: Option<NaiveTime>,
-    sof_tefila_gra: Option<NaiveTime>,
-    chatzot: Option<NaiveTime>,
-    mincha_gedola: Option<NaiveTime>,
-    mincha_ketana: Option<NaiveTime>,
-    plag: Option<NaiveTime>,
-    sunset: Option<NaiveTime>,
-    tzeit: Option<NaiveTime>,
-    tzeit_72: Option<NaiveTime>,
+    alot: Option<NaiveDateTime>,
+    misheyakir: Option<NaiveDateTime>,
+    sunrise: Option<NaiveDateTime>,
+    sof_shema_mga: Option<NaiveDateTime>,
+    sof_shema_gra: Option<NaiveDateTime>,
+    sof_tefila_mga: Option<NaiveDateTime>,
+    sof_tefila_gra: Option<NaiveDateTime>,
+    chatzot: Option<NaiveDateTime>,
+    mincha_gedola: Option<NaiveDateTime>,
+    mincha_ketana: Option<NaiveDateTime>,
+    plag: Option<NaiveDateTime>,
+    sunset: Option<NaiveDateTime>,
+    tzeit: Option<NaiveDateTime>,
+    tzeit_72: Option<NaiveDateTime>,
+    sunset_exact: Option<NaiveDateTime>,
+}
+
+impl CalculatedTimes {
+    /// Day offset of each time relative to `date` (0 when a time is missing)
+    fn day_offsets(&self, date: NaiveDate) -> ZmanimDayOffsets {
+        let offset = |t: Option<NaiveDateTime>| {
+            t.map(|t| (t.date() - date).num_days() as i8).unwrap_or(0)
+        };
+        
+        ZmanimDayOffsets {
+            alot_hashachar: offset(self.alot),
+            misheyakir: offset(self.misheyakir),
+            sunrise: offset(self.sunrise),
+            sof_zman_shema_mga: offset(self.sof_shema_mga),
+            sof_zman_shema_gra: offset(self.sof_shema_gra),
+            sof_zman_tefila_mga: offset(self.sof_tefila_mga),
+            sof_zman_tefila_gra: offset(self.sof_tefila_gra),
+            chatzot: offset(self.chatzot),
+            mincha_gedola: offset(self.mincha_gedola),
+            mincha_ketana: offset(self.mincha_ketana),
+            plag_hamincha: offset(self.plag),
+            sunset: offset(self.sunset),
+            tzeit_hakochavim: offset(self.tzeit),
+            tzeit_72_min: offset(self.tzeit_72),
+        }
+    }
+    
+    /// Angle-based times that could not be computed
+    fn reliability(&self) -> ZmanimReliability {
+        let times = [self.alot, self.misheyakir, self.sunrise, self.sunset, self.tzeit];
+        let missing: Vec<&'static str> = ANGLE_BASED_ZMANIM.into_iter()
+            .zip(times)
+            .filter(|(_, time)| time.is_none())
+            .map(|(name, _)| name)
+            .collect();
+        
+        if missing.is_empty() {
+            ZmanimReliability::Reliable
+        } else {
+            ZmanimReliability::PartialMissingAngles(missing)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -385,6 +1267,26 @@ mod tests {
         assert!(candle.is_some());
     }
     
+    #[test]
+    fn test_candle_lighting_rounding() {
+        let date = NaiveDate::from_ymd_opt(2024, 6, 14).unwrap();
+        let zmanim = ZmanimCalculator::new(GeoLocation::new_york()).calculate(date).unwrap();
+        let rounded = |sunset: &str, minutes| {
+            let zmanim = Zmanim { sunset_exact: Some(sunset.to_string()), ..zmanim.clone() };
+            let config = ZmanimConfig { candle_rounding_minutes: minutes, ..Default::default() };
+            ZmanimCalculator::new(GeoLocation::new_york())
+                .with_config(config)
+                .candle_lighting(&zmanim, 18)
+                .unwrap()
+                .unwrap()
+        };
+        assert_eq!(rounded("19:01:30", 0), "18:43");
+        assert_eq!(rounded("19:01:30", 1), "18:43");
+        assert_eq!(rounded("19:01:30", 5), "18:40");
+        assert_eq!(rounded("19:00:59", 5), "18:40");
+        assert_eq!(rounded("18:58:00", 5), "18:40");
+    }
+    
     #[test]
     fn test_geolocation_validation() {
         assert!(GeoLocation::new(91.0, 0.0).is_err());
@@ -394,6 +1296,62 @@ mod tests {
         assert!(GeoLocation::new(40.7128, -74.0060).is_ok());
     }
 
+    #[test]
+    fn test_geolocation_poles_and_antimeridian() {
+        let date = NaiveDate::from_ymd_opt(2024, 6, 21).unwrap();
+        for latitude in [90.0, -90.0] {
+            let pole = GeoLocation::new(latitude, 0.0).unwrap();
+            assert_eq!(
+                ZmanimCalculator::new(pole).calculate(date).unwrap_err(),
+                CalendarError::PolarLocation(latitude)
+            );
+        }
+        
+        // -180 and 180 are the same meridian
+        let east = GeoLocation::new(-17.0, 180.0).unwrap();
+        let west = GeoLocation::new(-17.0, -180.0).unwrap();
+        assert_eq!(west.longitude, 180.0);
+        assert_eq!(east, west);
+        let json = serde_json::to_string(&east).unwrap().replace("180.0", "-180.0");
+        assert_eq!(serde_json::from_str::<GeoLocation>(&json).unwrap().longitude, 180.0);
+        
+        let zmanim = ZmanimCalculator::new(east).calculate(date).unwrap();
+        assert!(zmanim.sunrise.is_some() && zmanim.sunset.is_some());
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_geolocation_from_json() {
+        let json = serde_json::to_string(&GeoLocation::jerusalem()).unwrap();
+        assert_eq!(GeoLocation::from_json(&json).unwrap(), GeoLocation::jerusalem());
+        
+        let bad_latitude = json.replace("31.7683", "200.0");
+        assert_eq!(GeoLocation::from_json(&bad_latitude), Err(CalendarError::InvalidLatitude(200.0)));
+        let bad_zone = json.replace(r#""timezone_id":null"#, r#""timezone_id":"Mars/Olympus""#);
+        assert!(matches!(GeoLocation::from_json(&bad_zone), Err(CalendarError::InvalidTimezone(_))));
+        let bad_elevation = json.replace("754.0", "100000.0");
+        assert_eq!(GeoLocation::from_json(&bad_elevation), Err(CalendarError::InvalidElevation(100000.0)));
+        assert!(matches!(GeoLocation::from_json("{"), Err(CalendarError::InvalidJson(_))));
+    }
+
+    #[test]
+    fn test_geolocation_deserialize_validates() {
+        let json = r#"{"latitude":31.77,"longitude":235.2,"elevation_meters":0.0,
+            "timezone_offset_minutes":120,"location_name":null}"#;
+        let err = serde_json::from_str::<GeoLocation>(json).unwrap_err();
+        assert!(err.to_string().contains("Invalid longitude: 235.2"), "{}", err);
+        
+        let zmanim_json = serde_json::to_string(
+            &ZmanimCalculator::new(GeoLocation::jerusalem())
+                .calculate(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())
+                .unwrap()
+        ).unwrap();
+        // Nested locations are validated too
+        let nested = zmanim_json.replace("31.7683", "-95.0");
+        assert!(serde_json::from_str::<Zmanim>(&nested).is_err());
+        assert!(serde_json::from_str::<Zmanim>(&zmanim_json).is_ok());
+    }
+
     #[test]
     fn test_jerusalem_sunrise_summer_solstice() {
         let loc = GeoLocation::jerusalem();
@@ -443,10 +1401,11 @@ mod tests {
         let candle = calc.candle_lighting(&zmanim, 18).unwrap();
         assert!(candle.is_some());
         // Candle should be 18 min before sunset
-        let sunset = NaiveTime::parse_from_str(zmanim.sunset.as_ref().unwrap(), "%H:%M").unwrap();
+        // Floored from the exact sunset, so up to a minute earlier than sunset - offset
+        let sunset = NaiveTime::parse_from_str(zmanim.sunset_exact.as_ref().unwrap(), "%H:%M:%S").unwrap();
         let candle_time = NaiveTime::parse_from_str(candle.as_ref().unwrap(), "%H:%M").unwrap();
-        let diff = sunset.signed_duration_since(candle_time).num_minutes();
-        assert_eq!(diff, 18, "Candle lighting should be 18 minutes before sunset");
+        let diff = sunset.signed_duration_since(candle_time).num_seconds();
+        assert!((18 * 60..(18 + 1) * 60).contains(&diff), "Candle lighting should be 18 minutes before sunset");
     }
 
     #[test]
@@ -457,12 +1416,45 @@ mod tests {
         let zmanim = calc.calculate(date).unwrap();
         let candle = calc.candle_lighting(&zmanim, 40).unwrap();
         assert!(candle.is_some());
-        let sunset = NaiveTime::parse_from_str(zmanim.sunset.as_ref().unwrap(), "%H:%M").unwrap();
+        // Floored from the exact sunset, so up to a minute earlier than sunset - offset
+        let sunset = NaiveTime::parse_from_str(zmanim.sunset_exact.as_ref().unwrap(), "%H:%M:%S").unwrap();
         let candle_time = NaiveTime::parse_from_str(candle.as_ref().unwrap(), "%H:%M").unwrap();
-        let diff = sunset.signed_duration_since(candle_time).num_minutes();
-        assert_eq!(diff, 40, "Candle lighting should be 40 minutes before sunset");
+        let diff = sunset.signed_duration_since(candle_time).num_seconds();
+        assert!((40 * 60..(40 + 1) * 60).contains(&diff), "Candle lighting should be 40 minutes before sunset");
     }
 
+    #[test]
+    fn test_candle_lighting_floors_to_minute() {
+        let calc = ZmanimCalculator::new(GeoLocation::jerusalem());
+        let mut zmanim = calc.calculate(NaiveDate::from_ymd_opt(2024, 6, 14).unwrap()).unwrap();
+        // 19:47:45 rounds to 19:48 for display, but candle lighting must not be late
+        zmanim.sunset = Some("19:48".to_string());
+        zmanim.sunset_exact = Some("19:47:45".to_string());
+        assert_eq!(calc.candle_lighting(&zmanim, 18).unwrap().unwrap(), "19:29");
+        
+        // Without the exact time, the rounded sunset is used
+        zmanim.sunset_exact = None;
+        assert_eq!(calc.candle_lighting(&zmanim, 18).unwrap().unwrap(), "19:30");
+    }
+    
+    #[test]
+    fn test_fixed_candle_time() {
+        let seven_pm = NaiveTime::from_hms_opt(19, 0, 0).unwrap();
+        let loc = GeoLocation::new_york()
+            .with_timezone_id("America/New_York").unwrap()
+            .with_fixed_candle_time(seven_pm);
+        let calc = ZmanimCalculator::new(loc);
+        
+        // Late June: sunset is after 20:00, so the fixed time wins
+        let summer = calc.calculate(NaiveDate::from_ymd_opt(2024, 6, 21).unwrap()).unwrap();
+        assert_eq!(calc.candle_lighting(&summer, 18).unwrap().unwrap(), "19:00");
+        
+        // December: sunset minus 18 minutes is well before 19:00
+        let winter = calc.calculate(NaiveDate::from_ymd_opt(2024, 12, 20).unwrap()).unwrap();
+        let candle = calc.candle_lighting(&winter, 18).unwrap().unwrap();
+        assert!(candle.as_str() < "17:00", "got {}", candle);
+    }
+    
     #[test]
     fn test_candle_lighting_no_sunset() {
         let zmanim = Zmanim {
@@ -482,6 +1474,10 @@ mod tests {
             sunset: None,
             tzeit_hakochavim: None,
             tzeit_72_min: None,
+            mean_time_offset_minutes: None,
+            day_offsets: ZmanimDayOffsets::default(),
+            sunset_exact: None,
+            reliability: ZmanimReliability::Reliable,
         };
         let loc = GeoLocation::jerusalem();
         let calc = ZmanimCalculator::new(loc);
@@ -492,12 +1488,25 @@ mod tests {
     #[test]
     fn test_geolocation_builders() {
         let loc = GeoLocation::new(40.0, -74.0).unwrap()
-            .with_elevation(100.0)
-            .with_timezone(-300)
+            .with_elevation(100.0).unwrap()
+            .with_timezone(-300).unwrap()
             .with_name("Test City");
         assert_eq!(loc.elevation_meters, 100.0);
         assert_eq!(loc.timezone_offset_minutes, -300);
         assert_eq!(loc.location_name.as_deref(), Some("Test City"));
+        
+        // A fixed offset applies all year; an IANA zone follows DST
+        let winter = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap().and_hms_opt(12, 0, 0).unwrap();
+        let summer = NaiveDate::from_ymd_opt(2024, 7, 15).unwrap().and_hms_opt(12, 0, 0).unwrap();
+        assert_eq!(loc.utc_offset_at(summer).local_minus_utc(), -300 * 60);
+        let zoned = loc.clone().with_timezone_id("America/New_York").unwrap();
+        assert_eq!(zoned.utc_offset_at(winter).local_minus_utc(), -5 * 3600);
+        assert_eq!(zoned.utc_offset_at(summer).local_minus_utc(), -4 * 3600);
+        
+        // The builders use the same bounds as validate()
+        let base = GeoLocation::new(40.0, -74.0).unwrap();
+        assert_eq!(base.clone().with_elevation(1e5), Err(CalendarError::InvalidElevation(1e5)));
+        assert!(matches!(base.with_timezone(900), Err(CalendarError::InvalidTimezone(_))));
     }
 
     #[test]
@@ -542,4 +1551,438 @@ mod tests {
         assert!(chatzot < sunset, "chatzot {} should be before sunset {}", chatzot, sunset);
         assert!(sunset < tzeit, "sunset {} should be before tzeit {}", sunset, tzeit);
     }
+
+    #[test]
+    fn test_timezone_id_validation() {
+        let loc = GeoLocation::new_york();
+        assert!(loc.clone().with_timezone_id("America/New_York").is_ok());
+        assert_eq!(
+            loc.with_timezone_id("Mars/Olympus_Mons").unwrap_err(),
+            CalendarError::InvalidTimezone("Mars/Olympus_Mons".to_string())
+        );
+    }
+
+    #[test]
+    fn test_timezone_id_applies_dst() {
+        // 2024-03-09 (Saturday) is EST; 2024-03-10 (Sunday) is EDT
+        let loc = GeoLocation::new_york().with_timezone_id("America/New_York").unwrap();
+        let calc = ZmanimCalculator::new(loc);
+        let parse = |s: &Option<String>| NaiveTime::parse_from_str(s.as_ref().unwrap(), "%H:%M").unwrap();
+
+        let saturday = calc.calculate(NaiveDate::from_ymd_opt(2024, 3, 9).unwrap()).unwrap();
+        let sunday = calc.calculate(NaiveDate::from_ymd_opt(2024, 3, 10).unwrap()).unwrap();
+        let diff = parse(&sunday.sunset).signed_duration_since(parse(&saturday.sunset)).num_minutes();
+        assert!((60..=62).contains(&diff),
+            "Sunset should jump ~1 hour across spring-forward, got {} minutes", diff);
+    }
+
+    #[test]
+    fn test_havdalah_on_dst_transition_saturday() {
+        // Saturday night before the US spring-forward (2024-03-10 02:00)
+        let loc = GeoLocation::new_york().with_timezone_id("America/New_York").unwrap();
+        let calc = ZmanimCalculator::new(loc);
+        let zmanim = calc.calculate(NaiveDate::from_ymd_opt(2024, 3, 9).unwrap()).unwrap();
+        let havdalah = calc.havdalah(&zmanim, 50).unwrap().unwrap();
+        let sunset = NaiveTime::parse_from_str(zmanim.sunset.as_ref().unwrap(), "%H:%M").unwrap();
+        let havdalah = NaiveTime::parse_from_str(&havdalah, "%H:%M").unwrap();
+        assert_eq!(havdalah.signed_duration_since(sunset).num_minutes(), 50);
+    }
+
+    #[test]
+    fn test_havdalah_skips_spring_forward_gap() {
+        // A Havdalah whose naive wall time would land in the lost hour
+        let loc = GeoLocation::new_york().with_timezone_id("America/New_York").unwrap();
+        let calc = ZmanimCalculator::new(loc.clone());
+        let mut zmanim = calc.calculate(NaiveDate::from_ymd_opt(2024, 3, 10).unwrap()).unwrap();
+        zmanim.sunset = Some("01:40".to_string());
+        let havdalah = calc.havdalah(&zmanim, 50).unwrap();
+        assert_eq!(havdalah.as_deref(), Some("03:30"));
+
+        // Without an IANA zone the naive wall-clock sum is kept
+        let naive = ZmanimCalculator::new(GeoLocation::new_york());
+        assert_eq!(naive.havdalah(&zmanim, 50).unwrap().as_deref(), Some("02:30"));
+    }
+
+    #[test]
+    fn test_resolve_wall_time_nonexistent() {
+        let loc = GeoLocation::new_york().with_timezone_id("America/New_York").unwrap();
+        let calc = ZmanimCalculator::new(loc);
+        let gap = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap().and_hms_opt(2, 30, 0).unwrap();
+        let resolved = calc.resolve_wall_time(gap);
+        assert_eq!(resolved, NaiveDate::from_ymd_opt(2024, 3, 10).unwrap().and_hms_opt(3, 30, 0).unwrap());
+    }
+
+    #[test]
+    fn test_display_timezone_offset() {
+        // Jerusalem sunset shown on a New York (UTC-5) clock is 7 hours earlier
+        let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let local = ZmanimCalculator::new(GeoLocation::jerusalem()).calculate(date).unwrap();
+        let config = ZmanimConfig { display_timezone_offset: Some(-300), ..Default::default() };
+        let shifted = ZmanimCalculator::new(GeoLocation::jerusalem())
+            .with_config(config)
+            .calculate(date)
+            .unwrap();
+
+        let parse = |s: &Option<String>| NaiveTime::parse_from_str(s.as_ref().unwrap(), "%H:%M").unwrap();
+        let diff = parse(&local.sunset).signed_duration_since(parse(&shifted.sunset)).num_minutes();
+        assert_eq!(diff, 7 * 60);
+    }
+
+    #[test]
+    fn test_calculate_utc() {
+        let date = NaiveDate::from_ymd_opt(2024, 3, 20).unwrap();
+        let utc = ZmanimCalculator::new(GeoLocation::jerusalem()).calculate_utc(date).unwrap();
+        let offset = utc.mean_time_offset_minutes.expect("offset should be set");
+        assert_eq!(offset, 141, "35.2137°E is about 141 minutes ahead of UTC");
+
+        // Applying the offset gives the sunrise on the local mean-time clock
+        let mean_time = GeoLocation::jerusalem().with_timezone(offset).unwrap();
+        let local = ZmanimCalculator::new(mean_time).calculate(date).unwrap();
+        let parse = |s: &Option<String>| NaiveTime::parse_from_str(s.as_ref().unwrap(), "%H:%M").unwrap();
+        let shifted = parse(&utc.sunrise) + Duration::minutes(offset as i64);
+        let diff = parse(&local.sunrise).signed_duration_since(shifted).num_minutes().abs();
+        assert!(diff <= 1, "UTC sunrise plus offset should match local mean sunrise");
+    }
+
+    #[test]
+    fn test_week_of() {
+        let calc = ZmanimCalculator::new(GeoLocation::jerusalem());
+        // Wednesday, March 13, 2024 → Sunday March 10 through Shabbat March 16
+        let week = calc.week_of(NaiveDate::from_ymd_opt(2024, 3, 13).unwrap()).unwrap();
+        
+        let sunday = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+        for (i, zmanim) in week.iter().enumerate() {
+            assert_eq!(zmanim.date, (sunday + Duration::days(i as i64)).to_string());
+        }
+        
+        // Friday's candle lighting comes from the Friday entry
+        let friday = &week[5];
+        assert_eq!(friday.date, "2024-03-15");
+        assert!(calc.candle_lighting(friday, 40).unwrap().is_some());
+    }
+    
+    #[test]
+    fn test_geolocation_cache_key() {
+        let a = GeoLocation::new(31.7683, 35.2137).unwrap();
+        let b = GeoLocation::new(31.76830004, 35.21369998).unwrap();
+        let far = GeoLocation::new(31.7783, 35.2137).unwrap();
+        assert_eq!(a.cache_key(6), b.cache_key(6));
+        assert_ne!(a.cache_key(6), far.cache_key(6));
+        assert_eq!(a.cache_key(6).0, 31_768_300);
+        
+        let seven_pm = NaiveTime::from_hms_opt(19, 0, 0).unwrap();
+        assert_ne!(a.cache_key(6), a.clone().with_fixed_candle_time(seven_pm).cache_key(6));
+        assert_ne!(a.cache_key(6), a.clone().with_early_shabbat_threshold(seven_pm).cache_key(6));
+        
+        // A zone moves every time by the DST hour
+        let zoned = GeoLocation::new_york().with_timezone_id("America/New_York").unwrap();
+        assert_ne!(GeoLocation::new_york().cache_key(6), zoned.cache_key(6));
+    }
+
+    #[test]
+    fn test_day_offsets_after_midnight_at_60n() {
+        // Near 60°N in June, nightfall spills past local midnight (UTC+3)
+        let loc = GeoLocation::new(59.0, 24.9).unwrap().with_timezone(180).unwrap();
+        let calc = ZmanimCalculator::new(loc);
+        let zmanim = calc.calculate(NaiveDate::from_ymd_opt(2024, 6, 1).unwrap()).unwrap();
+        assert_eq!(zmanim.tzeit_hakochavim.as_deref(), Some("00:42"));
+        assert_eq!(zmanim.day_offsets.tzeit_hakochavim, 1);
+        assert_eq!(zmanim.day_offsets.sunrise, 0);
+        assert_eq!(zmanim.day_offsets.sunset, 0);
+        
+        // At 60°N on the solstice 72 minutes after sunset lands on midnight
+        let loc = GeoLocation::new(60.0, 24.9).unwrap().with_timezone(180).unwrap();
+        let calc = ZmanimCalculator::new(loc);
+        let zmanim = calc.calculate(NaiveDate::from_ymd_opt(2024, 6, 21).unwrap()).unwrap();
+        assert_eq!(zmanim.tzeit_hakochavim, None);
+        assert_eq!(zmanim.tzeit_72_min.as_deref(), Some("00:00"));
+        assert_eq!(zmanim.day_offsets.tzeit_72_min, 1);
+    }
+
+    #[test]
+    fn test_as_datetimes_rolls_past_midnight() {
+        let loc = GeoLocation::new(59.0, 24.9).unwrap().with_timezone(180).unwrap();
+        let calc = ZmanimCalculator::new(loc);
+        let zmanim = calc.calculate(NaiveDate::from_ymd_opt(2024, 6, 1).unwrap()).unwrap();
+        let times = zmanim.as_datetimes();
+        
+        let next_day = NaiveDate::from_ymd_opt(2024, 6, 2).unwrap();
+        assert_eq!(times.tzeit_hakochavim, Some(next_day.and_hms_opt(0, 42, 0).unwrap()));
+        assert_eq!(times.sunset.unwrap().date(), NaiveDate::from_ymd_opt(2024, 6, 1).unwrap());
+        assert!(times.sunset < times.tzeit_hakochavim);
+    }
+
+    #[test]
+    fn test_calculate_datetimes_matches_formatted() {
+        let calc = ZmanimCalculator::new(GeoLocation::new(59.0, 24.9).unwrap().with_timezone(180).unwrap());
+        let minute = |t: Option<NaiveDateTime>| t.map(|t| t.format("%Y-%m-%d %H:%M").to_string());
+        for date in [NaiveDate::from_ymd_opt(2024, 6, 1).unwrap(), NaiveDate::from_ymd_opt(2024, 12, 21).unwrap()] {
+            let precise = calc.calculate_datetimes(date).unwrap();
+            let formatted = calc.calculate(date).unwrap().as_datetimes();
+            assert_eq!(minute(precise.sunrise), minute(formatted.sunrise));
+            assert_eq!(minute(precise.plag_hamincha), minute(formatted.plag_hamincha));
+            assert_eq!(minute(precise.tzeit_hakochavim), minute(formatted.tzeit_hakochavim));
+            assert_eq!(precise.alot_hashachar.is_some(), formatted.alot_hashachar.is_some());
+        }
+    }
+
+    #[test]
+    fn test_mga_basis_changes_sof_zman_shema() {
+        let date = NaiveDate::from_ymd_opt(2024, 12, 21).unwrap();
+        let shema = |mga_basis| {
+            let config = ZmanimConfig { mga_basis, ..Default::default() };
+            ZmanimCalculator::new(GeoLocation::jerusalem())
+                .with_config(config)
+                .calculate(date)
+                .unwrap()
+                .sof_zman_shema_mga
+                .unwrap()
+        };
+        
+        let fixed = shema(MgaBasis::Fixed72);
+        let zmaniyos = shema(MgaBasis::Zmaniyos72);
+        let degrees = shema(MgaBasis::Degrees16_1);
+        assert_ne!(fixed, zmaniyos);
+        assert_ne!(fixed, degrees);
+        assert_ne!(zmaniyos, degrees);
+        
+        // Winter shaot zmaniyot are short, so 72 proportional minutes start later
+        assert!(zmaniyos > fixed, "{} should be after {}", zmaniyos, fixed);
+        assert_eq!(fixed, ZmanimCalculator::new(GeoLocation::jerusalem())
+            .calculate(date).unwrap().sof_zman_shema_mga.unwrap());
+    }
+
+    #[test]
+    fn test_reference_meridian_shifts_sunset() {
+        // Madrid sits ~18.7° west of the CET meridian (15°E)
+        let madrid = GeoLocation::new(40.4168, -3.7038).unwrap().with_timezone(60).unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let sunset = |config: ZmanimConfig| {
+            let zmanim = ZmanimCalculator::new(madrid.clone())
+                .with_config(config)
+                .calculate(date)
+                .unwrap();
+            NaiveTime::parse_from_str(&zmanim.sunset.unwrap(), "%H:%M").unwrap()
+        };
+        
+        let observer = sunset(ZmanimConfig::default());
+        let meridian = sunset(ZmanimConfig { reference_meridian: Some(15.0), ..Default::default() });
+        
+        // 18.7° × 4 minutes/degree ≈ 75 minutes earlier at the meridian
+        let diff = observer.signed_duration_since(meridian).num_minutes();
+        assert!((73..=77).contains(&diff), "expected ~75 min, got {}", diff);
+    }
+
+    #[test]
+    fn test_horizon_elevation_angle_earlier_sunset() {
+        let date = NaiveDate::from_ymd_opt(2024, 6, 15).unwrap();
+        let times = |angle| {
+            let config = ZmanimConfig { horizon_elevation_angle: angle, ..Default::default() };
+            let zmanim = ZmanimCalculator::new(GeoLocation::jerusalem())
+                .with_config(config)
+                .calculate(date)
+                .unwrap();
+            let parse = |s: Option<String>| NaiveTime::parse_from_str(&s.unwrap(), "%H:%M").unwrap();
+            (parse(zmanim.sunrise), parse(zmanim.sunset))
+        };
+        
+        let (flat_rise, flat_set) = times(None);
+        let (ridge_rise, ridge_set) = times(Some(2.0));
+        assert!(ridge_set < flat_set, "{} should be before {}", ridge_set, flat_set);
+        assert!(ridge_rise > flat_rise, "{} should be after {}", ridge_rise, flat_rise);
+        assert_eq!(times(Some(0.0)), (flat_rise, flat_set));
+    }
+
+    #[test]
+    fn test_distance_km() {
+        let jerusalem = GeoLocation::jerusalem();
+        let new_york = GeoLocation::new_york();
+        let d = jerusalem.distance_km(&new_york);
+        assert!((9000.0..9200.0).contains(&d), "Jerusalem–New York was {} km", d);
+        assert_eq!(jerusalem.distance_km(&jerusalem), 0.0);
+    }
+    
+    #[test]
+    fn test_nearest_known_city() {
+        // Near Dizengoff Square, Tel Aviv
+        let point = GeoLocation::new(32.0776, 34.7740).unwrap();
+        let (name, distance) = point.nearest_known_city();
+        assert_eq!(name, "Tel Aviv");
+        assert!(distance < 2.0, "distance was {} km", distance);
+    }
+
+    #[test]
+    fn test_timeline_sorted() {
+        let calc = ZmanimCalculator::new(GeoLocation::jerusalem());
+        let zmanim = calc.calculate(NaiveDate::from_ymd_opt(2024, 6, 15).unwrap()).unwrap();
+        let timeline = zmanim.timeline();
+        
+        assert_eq!(timeline.len(), 14);
+        assert_eq!(timeline[0].0, "Alot HaShachar");
+        assert!(timeline.windows(2).all(|w| w[0].1 < w[1].1), "{:?}", timeline);
+    }
+    
+    #[test]
+    fn test_timeline_after_midnight_sorts_last() {
+        // Near 60°N in June tzeit is after midnight; alot never occurs
+        let loc = GeoLocation::new(59.0, 24.9).unwrap().with_timezone(180).unwrap();
+        let zmanim = ZmanimCalculator::new(loc)
+            .calculate(NaiveDate::from_ymd_opt(2024, 6, 1).unwrap())
+            .unwrap();
+        let timeline = zmanim.timeline();
+        
+        assert!(timeline.iter().all(|(label, _)| *label != "Alot HaShachar"));
+        assert_eq!(timeline.last().unwrap(), &("Tzeit HaKochavim", "00:42".to_string()));
+    }
+
+    #[test]
+    fn test_reliability_high_latitude_summer() {
+        // At 62°N in June the sun never reaches 16.1° (or 8.5°) below the horizon
+        let loc = GeoLocation::new(62.0, 25.0).unwrap().with_timezone(180).unwrap();
+        let zmanim = ZmanimCalculator::new(loc)
+            .calculate(NaiveDate::from_ymd_opt(2024, 6, 21).unwrap())
+            .unwrap();
+        
+        assert!(zmanim.sunrise.is_some());
+        match &zmanim.reliability {
+            ZmanimReliability::PartialMissingAngles(missing) => {
+                assert!(missing.contains(&"alot_hashachar"), "{:?}", missing);
+                assert!(!missing.contains(&"sunrise"), "{:?}", missing);
+            }
+            ZmanimReliability::Reliable => panic!("expected missing angles at 62°N"),
+        }
+        let json = serde_json::to_string(&zmanim).unwrap();
+        assert_eq!(serde_json::from_str::<Zmanim>(&json).unwrap(), zmanim);
+        
+        // Jerusalem has every angle year-round
+        let jerusalem = ZmanimCalculator::new(GeoLocation::jerusalem())
+            .calculate(NaiveDate::from_ymd_opt(2024, 6, 21).unwrap())
+            .unwrap();
+        assert_eq!(jerusalem.reliability, ZmanimReliability::Reliable);
+    }
+    
+    #[test]
+    fn test_misheyakir_threshold() {
+        let date = NaiveDate::from_ymd_opt(2024, 3, 20).unwrap();
+        let with = |misheyakir| {
+            ZmanimCalculator::new(GeoLocation::jerusalem())
+                .with_config(ZmanimConfig { misheyakir, ..Default::default() })
+                .calculate(date)
+                .unwrap()
+        };
+        let standard = with(ZmanThreshold::default());
+        let shallow = with(ZmanThreshold::Degrees(10.2));
+        let fixed = with(ZmanThreshold::MinutesBeforeSunrise(60));
+        
+        // A shallower angle is reached later, between 11.5° and sunrise
+        let alot = standard.alot_hashachar.clone().unwrap();
+        let at_11_5 = standard.misheyakir.clone().unwrap();
+        let at_10_2 = shallow.misheyakir.clone().unwrap();
+        let sunrise = standard.sunrise.clone().unwrap();
+        assert!(alot < at_11_5 && at_11_5 < at_10_2 && at_10_2 < sunrise,
+            "{} {} {} {}", alot, at_11_5, at_10_2, sunrise);
+        
+        let parse = |s: &str| NaiveTime::parse_from_str(s, "%H:%M").unwrap();
+        let before_sunrise = parse(&sunrise) - parse(fixed.misheyakir.as_ref().unwrap());
+        assert_eq!(before_sunrise.num_minutes(), 60);
+    }
+
+    #[test]
+    fn test_solar_debug_mid_february() {
+        let calc = ZmanimCalculator::new(GeoLocation::jerusalem());
+        let date = NaiveDate::from_ymd_opt(2024, 2, 12).unwrap();
+        let debug = calc.solar_debug(date);
+        
+        // The equation of time bottoms out near -14.2 minutes in mid-February
+        assert!((debug.equation_of_time_min + 14.2).abs() < 0.3, "{}", debug.equation_of_time_min);
+        assert!((debug.declination_deg + 13.9).abs() < 0.5, "{}", debug.declination_deg);
+        
+        // Solar noon sits midway between sunrise and sunset
+        let zmanim = calc.calculate(date).unwrap();
+        let chatzot = NaiveTime::parse_from_str(&zmanim.chatzot.unwrap(), "%H:%M").unwrap();
+        assert!((debug.solar_noon_local.time() - chatzot).num_seconds().abs() <= 90);
+        
+        let precise = ZmanimCalculator::new(GeoLocation::jerusalem())
+            .with_config(ZmanimConfig { high_precision: true, ..Default::default() })
+            .solar_debug(date);
+        assert!((precise.equation_of_time_min - debug.equation_of_time_min).abs() < 0.2);
+    }
+    
+    #[test]
+    fn test_fast_times() {
+        let calc = ZmanimCalculator::new(GeoLocation::jerusalem());
+        let day = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        
+        // 17 Tammuz 5784 (Tuesday, July 23, 2024): alot to tzeit
+        let tammuz = day(2024, 7, 23);
+        let zmanim = calc.calculate(tammuz).unwrap();
+        assert_eq!(
+            calc.fast_times(tammuz, Holiday::ShivaAsarBTammuz).unwrap(),
+            Some((zmanim.alot_hashachar.clone().unwrap(), zmanim.tzeit_hakochavim.clone().unwrap()))
+        );
+        let from_sunrise = ZmanimCalculator::new(GeoLocation::jerusalem())
+            .with_config(ZmanimConfig { minor_fast_start: FastStart::Sunrise, ..Default::default() });
+        assert_eq!(from_sunrise.fast_times(tammuz, Holiday::ShivaAsarBTammuz).unwrap().unwrap().0, zmanim.sunrise.unwrap());
+        
+        // Tisha B'Av 5784 (Tuesday, August 13, 2024) begins at Monday's sunset
+        let av = day(2024, 8, 13);
+        let eve = calc.calculate(day(2024, 8, 12)).unwrap();
+        assert_eq!(
+            calc.fast_times(av, Holiday::TishaBAv).unwrap(),
+            Some((eve.sunset.unwrap(), calc.calculate(av).unwrap().tzeit_hakochavim.unwrap()))
+        );
+        
+        assert_eq!(calc.fast_times(av, Holiday::ShivaAsarBTammuz).unwrap(), None);
+        assert_eq!(calc.fast_times(tammuz, Holiday::Purim).unwrap(), None);
+        
+        // In 5782 both summer fasts fell on Shabbat and were kept on Sunday
+        assert_eq!(calc.fast_times(day(2022, 7, 16), Holiday::ShivaAsarBTammuz).unwrap(), None);
+        assert!(calc.fast_times(day(2022, 7, 17), Holiday::ShivaAsarBTammuz).unwrap().is_some());
+        assert_eq!(calc.fast_times(day(2022, 8, 6), Holiday::TishaBAv).unwrap(), None);
+        let postponed = calc.fast_times(day(2022, 8, 7), Holiday::TishaBAv).unwrap().unwrap();
+        assert_eq!(postponed.0, calc.calculate(day(2022, 8, 6)).unwrap().sunset.unwrap());
+        
+        // 13 Adar 5784 was Shabbat, so Taanit Esther moved back to Thursday
+        assert_eq!(calc.fast_times(day(2024, 3, 23), Holiday::TaanitEsther).unwrap(), None);
+        assert!(calc.fast_times(day(2024, 3, 21), Holiday::TaanitEsther).unwrap().is_some());
+    }
+    
+    #[test]
+    fn test_erev_pesach_times() {
+        // 14 Nisan 5784 = April 22, 2024
+        let calc = ZmanimCalculator::new(GeoLocation::jerusalem());
+        let date = NaiveDate::from_ymd_opt(2024, 4, 22).unwrap();
+        let times = calc.erev_pesach_times(date).unwrap().unwrap();
+        let zmanim = calc.calculate(date).unwrap();
+        
+        let achilat = times.sof_zman_achilat_chametz.unwrap();
+        let biur = times.sof_zman_biur_chametz.unwrap();
+        assert_eq!(Some(&achilat), zmanim.sof_zman_tefila_mga.as_ref());
+        assert!(achilat < biur, "{} {}", achilat, biur);
+        assert!(biur < zmanim.chatzot.unwrap());
+        
+        assert_eq!(calc.erev_pesach_times(NaiveDate::from_ymd_opt(2024, 4, 23).unwrap()), Ok(None));
+    }
+
+    #[test]
+    fn test_high_precision_matches_standard_at_equinox() {
+        let date = NaiveDate::from_ymd_opt(2024, 3, 20).unwrap();
+        let standard = ZmanimCalculator::new(GeoLocation::jerusalem()).calculate(date).unwrap();
+        let precise = ZmanimCalculator::new(GeoLocation::jerusalem())
+            .with_config(ZmanimConfig { high_precision: true, ..Default::default() })
+            .calculate(date)
+            .unwrap();
+        
+        let minutes = |s: &Option<String>| {
+            let t = NaiveTime::parse_from_str(s.as_ref().unwrap(), "%H:%M").unwrap();
+            t.signed_duration_since(NaiveTime::MIN).num_minutes()
+        };
+        for (a, b) in [
+            (&standard.sunrise, &precise.sunrise),
+            (&standard.sunset, &precise.sunset),
+            (&standard.tzeit_hakochavim, &precise.tzeit_hakochavim),
+        ] {
+            assert!((minutes(a) - minutes(b)).abs() <= 1, "{:?} vs {:?}", a, b);
+        }
+    }
 }
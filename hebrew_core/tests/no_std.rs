@@ -0,0 +1,36 @@
+//! Uses hebrew_core the way an embedded zmanim clock would: from `no_std`
+//! code, with only `core` and `alloc`.
+//!
+//! Run against the `no_std` build with
+//! `cargo test -p hebrew_core --no-default-features`.
+
+#![no_std]
+
+// The test harness itself needs std; the code under test does not use it
+extern crate std;
+
+use chrono::{NaiveDate, NaiveTime, Timelike, Weekday};
+use hebrew_core::calendar::HebrewMonth;
+use hebrew_core::{DateConverter, GeoLocation, HebrewDate, ZmanimCalculator};
+
+#[test]
+fn converts_dates() {
+    let date = NaiveDate::from_ymd_opt(2024, 10, 3).unwrap();
+    let hebrew = DateConverter::gregorian_to_hebrew(date).unwrap();
+    assert_eq!(hebrew, HebrewDate::new(5785, HebrewMonth::Tishrei, 1));
+    assert_eq!(hebrew.try_day_of_week().unwrap(), Weekday::Thu);
+    assert_eq!(DateConverter::hebrew_to_gregorian(hebrew).unwrap(), date);
+}
+
+#[test]
+fn calculates_sunrise_and_sunset() {
+    let calc = ZmanimCalculator::new(GeoLocation::jerusalem());
+    let times = calc.calculate_datetimes(NaiveDate::from_ymd_opt(2024, 6, 21).unwrap()).unwrap();
+    
+    // Jerusalem at the solstice, in standard time (UTC+2): about 04:33 and 18:47
+    let sunrise = times.sunrise.unwrap().time();
+    let sunset = times.sunset.unwrap().time();
+    assert!(sunrise > NaiveTime::from_hms_opt(4, 25, 0).unwrap() && sunrise < NaiveTime::from_hms_opt(4, 40, 0).unwrap());
+    assert!(sunset > NaiveTime::from_hms_opt(18, 40, 0).unwrap() && sunset < NaiveTime::from_hms_opt(18, 55, 0).unwrap());
+    assert_eq!(times.chatzot.unwrap().hour(), 11);
+}
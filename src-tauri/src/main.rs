@@ -3,7 +3,7 @@
     windows_subsystem = "windows"
 )]
 
-use hebrew_core::{DailyData, HebrewCalendar};
+use hebrew_core::{CalendarSystem, DailyData, HebrewCalendar};
 use std::sync::Mutex;
 use tauri::{Manager, State};
 
@@ -34,7 +34,9 @@ fn get_calendar_data(
             hebrew_core::zmanim::GeoLocation::new(lat, long)
                 .map_err(|e| e.to_string())?
                 .with_timezone(*state.default_tz.lock().map_err(|e| e.to_string())?)
-                .with_elevation(*state.default_elevation.lock().map_err(|e| e.to_string())?),
+                .map_err(|e| e.to_string())?
+                .with_elevation(*state.default_elevation.lock().map_err(|e| e.to_string())?)
+                .map_err(|e| e.to_string())?,
         )
     } else {
         let dlat = *state.default_lat.lock().map_err(|e| e.to_string())?;
@@ -45,11 +47,13 @@ fn get_calendar_data(
             hebrew_core::zmanim::GeoLocation::new(dlat, dlong)
                 .map_err(|e| e.to_string())?
                 .with_timezone(dtz)
-                .with_elevation(delev),
+                .map_err(|e| e.to_string())?
+                .with_elevation(delev)
+                .map_err(|e| e.to_string())?,
         )
     };
 
-    HebrewCalendar::calculate_day(date, location, offset)
+    HebrewCalendar::calculate_day(date, CalendarSystem::Gregorian, location, offset)
         .map_err(|e| e.to_string())
 }
 
@@ -68,10 +72,11 @@ fn get_zmanim(
     let tz = *state.default_tz.lock().map_err(|e| e.to_string())?;
     let mut loc = hebrew_core::zmanim::GeoLocation::new(lat, long)
         .map_err(|e| e.to_string())?
-        .with_timezone(tz);
+        .with_timezone(tz)
+        .map_err(|e| e.to_string())?;
 
     if let Some(elev) = elevation {
-        loc = loc.with_elevation(elev);
+        loc = loc.with_elevation(elev).map_err(|e| e.to_string())?;
     }
 
     let calc = hebrew_core::zmanim::ZmanimCalculator::new(loc);